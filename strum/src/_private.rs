@@ -0,0 +1,262 @@
+//! Helpers called from the code generated by `strum_macros`. Nothing in here is part of the
+//! public API, and it can change in any release.
+
+use core::fmt::{self, Write};
+
+/// How `EnumString` normalizes an input before comparing it to the serializations of a
+/// variant. The derive macro applies the same steps to the serializations at compile time.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Normalize {
+    /// Strip leading and trailing whitespace.
+    pub trim: bool,
+    /// Apply Unicode full case folding to every char, see [`fold_case`].
+    pub case_insensitive: bool,
+    /// Drop every `-`, `_` and space.
+    pub separator_insensitive: bool,
+}
+
+impl Normalize {
+    /// Normalizes `input` into `buf`. Returns `None` if the result doesn't fit, in which case it
+    /// can't match any serialization either, since `N` is the length of the longest one.
+    pub fn apply<'a, const N: usize>(
+        &self,
+        input: &'a str,
+        buf: &'a mut [u8; N],
+    ) -> Option<&'a str> {
+        let input = if self.trim { input.trim() } else { input };
+        if !self.case_insensitive && !self.separator_insensitive {
+            return Some(input);
+        }
+
+        let mut len = 0;
+        let mut push = |c: char| {
+            let end = len + c.len_utf8();
+            if end > N {
+                return None;
+            }
+
+            c.encode_utf8(&mut buf[len..end]);
+            len = end;
+            Some(())
+        };
+
+        for c in input.chars() {
+            if self.separator_insensitive && matches!(c, '-' | '_' | ' ') {
+                continue;
+            }
+
+            if self.case_insensitive {
+                fold_case(c, &mut push)?;
+            } else {
+                push(c)?;
+            }
+        }
+
+        core::str::from_utf8(&buf[..len]).ok()
+    }
+}
+
+/// Passes the Unicode full case folding of `c` to `push`, so that e.g. `"Straße"`, `"STRASSE"`
+/// and `"strasse"` all fold to the same string, as do `"ΣΟΦΟΣ"` and `"σοφος"`.
+///
+/// Instead of shipping the case folding tables, each char is uppercased and then lowercased,
+/// which agrees with them except for the two chars handled explicitly. Must stay in sync with
+/// the copy in `strum_macros`, which folds the serializations.
+pub fn fold_case<F: FnMut(char) -> Option<()>>(c: char, push: &mut F) -> Option<()> {
+    // The dotless `ı` only folds to itself, but uppercases to `I`.
+    if c == 'ı' {
+        return push(c);
+    }
+
+    for upper in c.to_uppercase() {
+        for lower in upper.to_lowercase() {
+            // The capital `ẞ` lowercases to `ß` instead of folding to `ss`.
+            if lower == 'ß' {
+                push('s')?;
+                push('s')?;
+            } else {
+                push(lower)?;
+            }
+        }
+    }
+
+    Some(())
+}
+
+/// Copies `input` into `buf` with ASCII letters lowercased. Returns `None` if it doesn't fit.
+pub fn ascii_lowercase<'a, const N: usize>(input: &str, buf: &'a mut [u8; N]) -> Option<&'a str> {
+    let buf = buf.get_mut(..input.len())?;
+    buf.copy_from_slice(input.as_bytes());
+    buf.make_ascii_lowercase();
+    core::str::from_utf8(buf).ok()
+}
+
+/// Compares two strings byte by byte in a `const fn`, where `==` on `str` isn't available.
+pub const fn const_eq(a: &str, b: &str, ascii_case_insensitive: bool) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] && !(ascii_case_insensitive && a[i].eq_ignore_ascii_case(&b[i])) {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+/// Matches `input` against a `pattern` where `?` stands for any one char and `*` for any run of
+/// chars, possibly empty. A `*` that fails to match is retried one char further, so this never
+/// backtracks more than once per char of input.
+pub fn glob(pattern: &str, input: &str, ascii_case_insensitive: bool) -> bool {
+    let (p, s) = (pattern.as_bytes(), input.as_bytes());
+    let (mut pi, mut si) = (0, 0);
+    // The pattern after the last `*`, and how much of the input that `*` has swallowed.
+    let mut star = None;
+    while si < s.len() {
+        match p.get(pi) {
+            Some(b'*') => {
+                pi += 1;
+                star = Some((pi, si));
+            }
+            Some(b'?') => {
+                pi += 1;
+                si = next_char(s, si);
+            }
+            Some(b)
+                if *b == s[si] || (ascii_case_insensitive && b.eq_ignore_ascii_case(&s[si])) =>
+            {
+                pi += 1;
+                si += 1;
+            }
+            _ => match star {
+                Some((after_star, swallowed)) => {
+                    let swallowed = next_char(s, swallowed);
+                    star = Some((after_star, swallowed));
+                    pi = after_star;
+                    si = swallowed;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    p[pi..].iter().all(|b| *b == b'*')
+}
+
+/// The index of the char after the one starting at `i`.
+fn next_char(s: &[u8], i: usize) -> usize {
+    let mut i = i + 1;
+    while i < s.len() && s[i] & 0xC0 == 0x80 {
+        i += 1;
+    }
+
+    i
+}
+
+/// Parses bytes with `FromStr`. Input that isn't UTF-8 can't match any serialization, so it never
+/// reaches a default variant: `not_found` builds the error from it instead. With `std` it sees the
+/// input converted lossily, without `std` only the part before the first invalid byte.
+pub fn parse_bytes<T: core::str::FromStr>(
+    bytes: &[u8],
+    not_found: impl FnOnce(&str) -> T::Err,
+) -> Result<T, T::Err> {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => T::from_str(s),
+        #[cfg(feature = "std")]
+        Err(_) => Err(not_found(&std::string::String::from_utf8_lossy(bytes))),
+        #[cfg(not(feature = "std"))]
+        Err(e) => Err(not_found(
+            core::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default(),
+        )),
+    }
+}
+
+/// Writes `args` into `f` the way `Formatter::pad` writes a `str`: the output is truncated to the
+/// precision and padded to the width with the fill and alignment of `f`, left aligned by default.
+/// Nothing is allocated, so when padding is needed `args` is formatted twice, once to measure it.
+pub fn pad_fmt(f: &mut fmt::Formatter, args: fmt::Arguments) -> fmt::Result {
+    if f.width().is_none() && f.precision().is_none() {
+        return f.write_fmt(args);
+    }
+
+    let mut counter = CharCounter(0);
+    fmt::write(&mut counter, args)?;
+    let len = match f.precision() {
+        Some(precision) => counter.0.min(precision),
+        None => counter.0,
+    };
+
+    let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, (padding + 1) / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+
+    fmt::write(&mut Truncate { f, remaining: len }, args)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+
+    Ok(())
+}
+
+/// Counts the chars written to it.
+struct CharCounter(usize);
+
+impl fmt::Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Passes the first `remaining` chars written to it on to `f`, and drops the rest.
+struct Truncate<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    remaining: usize,
+}
+
+impl fmt::Write for Truncate<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = match s.char_indices().nth(self.remaining) {
+            Some((end, _)) => end,
+            None => s.len(),
+        };
+
+        self.remaining -= s[..end].chars().count();
+        self.f.write_str(&s[..end])
+    }
+}
+
+#[cfg(feature = "std")]
+pub use std::{borrow::Cow, ffi::OsStr, string::String};
+
+/// Keeps the impls passed to it only if strum was built with `std`. The derive macros can't see
+/// strum's features, so this decides for them.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_if_std {
+    ($($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_if_std {
+    ($($tt:tt)*) => {};
+}
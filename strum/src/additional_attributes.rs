@@ -50,35 +50,213 @@
 //! - You can also apply the `#[strum(ascii_case_insensitive)]` attribute to the enum,
 //!   and this has the same effect of applying it to every variant.
 //!
+//! - `#[strum(case_insensitive)]`, `#[strum(trim)]` and `#[strum(separator_insensitive)]` have the same
+//!   effect as applying them to every variant.
+//!
+//! - `#[strum(suggest)]` makes `EnumString` rank the accepted strings by their edit distance to
+//!   an input that failed to parse. The closest ones are available through
+//!   `VariantNotFound::suggestions` and are printed as "did you mean ...?" by the error's `Display`.
+//!   Suggestions are only computed once parsing has failed, so successful parses don't pay for them.
+//!
+//! - `#[strum(parse_err_ty = Error, parse_err_context_fn = path)]` is an alternative to `parse_err_fn`.
+//!   The function receives a `strum::ParseErrorContext` holding the input, the name of the enum and
+//!   every accepted string instead of just the input. With `suggest`, the context also holds the
+//!   closest accepted strings.
+//!
+//! - `#[strum(parse_fields)]` has the same effect as applying `parse_fields` to every variant.
+//!
+//! - `#[strum(parse_any_case)]` makes `EnumString` also accept the variant names in every case style
+//!   supported by `serialize_all`, so `HttpServer` parses from `"http_server"`, `"httpServer"`,
+//!   `"HTTP-SERVER"` and so on. `#[strum(parse_case_styles("camelCase", "SCREAMING_SNAKE_CASE"))]`
+//!   limits this to the listed styles. Only the output style from `serialize_all` is listed in
+//!   `VariantNotFound::expected`. Variants with an explicit `serialize` or `to_string` are not
+//!   affected. Two variants accepting the same string is a compile error.
+//!
+//! - `#[strum(unique_prefix)]` makes `EnumString` accept any abbreviation that is the start of
+//!   exactly one variant's serializations, the way `git sta` expands to `git status`. An
+//!   abbreviation shared by several variants fails with `ParseError::Ambiguous`, which lists the
+//!   candidates. The enum also gets a `completions(prefix)` method that iterates over the accepted
+//!   strings starting with `prefix`, for tab completion. Abbreviations of `ascii_case_insensitive`
+//!   variants ignore ASCII case, those of other variants must match exactly. The prefix tree is
+//!   generated at compile time.
+//!
+//! - `#[strum(const_from_str)]` makes `EnumString` also emit `const fn from_str_const(&str) -> Self`
+//!   and `const fn try_from_str_const(&str) -> Option<Self>`, the parsing counterpart of
+//!   `const_into_str`. `from_str_const` panics when nothing matches, so an invalid string in a
+//!   `const` fails to compile. Only enums of unit variants are supported. The exact serializations
+//!   and `ascii_case_insensitive` are honoured, but abbreviations from `unique_prefix` are not, and
+//!   `trim`, `case_insensitive` and `separator_insensitive` are rejected.
+//!
+//!   ```rust
+//!   #[derive(Debug, PartialEq, strum_macros::EnumString)]
+//!   #[strum(serialize_all = "lowercase", const_from_str)]
+//!   enum Level {
+//!       Warn,
+//!       Error,
+//!   }
+//!
+//!   const LEVEL: Level = Level::from_str_const("warn");
+//!   assert_eq!(Level::Warn, LEVEL);
+//!   ```
+//!
+//! - `#[strum(use_decision_tree)]` makes `EnumString` dispatch on the length of the input and then on
+//!   single bytes chosen at compile time, instead of comparing the input to every serialization in
+//!   turn. Unlike `use_phf` it needs no extra dependency and handles `ascii_case_insensitive`
+//!   without a linear scan. The two can't be combined.
+//!
+//! - `#[strum(extra_try_from)]` makes `EnumString` also derive `TryFrom<&[u8]>`, and with the `std`
+//!   feature `TryFrom<&OsStr>`, `TryFrom<String>` and `TryFrom<Cow<str>>`. Bytes are matched without
+//!   converting them to a `str` first. Input that isn't UTF-8 gets the not-found error, or the
+//!   `parse_err_fn` error built from it, and never the `default` variant.
+//!
+//! - `#[strum(parse_list)]` derives `parse_list` and `collect_list`, which parse every item of a
+//!   delimited string into a [`ParseList`](crate::ParseList) or a collection. They panic if the
+//!   separator is empty. With a `default` variant, duplicates of it are told apart by value, so its
+//!   field must implement `PartialEq`.
+//!
+//! - `#[strum(parse_with_info)]` derives `parse_with_info`, which parses like `FromStr` and also
+//!   returns a [`ParseInfo`](crate::ParseInfo) telling whether the input was a `deprecated_alias`.
+//!
+//! - `#[strum(parse_borrowed)]` derives `parse_borrowed(&'a str)` for an enum with a single lifetime
+//!   parameter `'a`. It parses like `FromStr`, or like `TryFrom<&'a str>` if the `default` variant
+//!   borrows from the input.
+//!
+//! - `#[strum(parse_prefix)]` derives `parse_prefix(s)`, which returns the variant whose serialization
+//!   is the longest prefix of `s` along with the rest of `s`, for tokenizers. It's a compile error to
+//!   combine it with `trim`, `case_insensitive` or `separator_insensitive`.
+//!
+//! - `#[strum(parse_repr)]` makes `EnumString` also accept the discriminant of a variant, written as a
+//!   number of the enum's `#[repr]` type (`usize` without one). Numbers are only tried once no
+//!   serialization matched, and map to the same variants as `FromRepr::from_repr`.
+//!
+//!   ```rust
+//!   use std::str::FromStr;
+//!
+//!   #[derive(Debug, PartialEq, strum_macros::EnumString)]
+//!   #[strum(serialize_all = "lowercase", parse_repr)]
+//!   #[repr(u8)]
+//!   enum Level {
+//!       Info = 1,
+//!       Warn,
+//!   }
+//!
+//!   assert_eq!(Ok(Level::Warn), Level::from_str("warn"));
+//!   assert_eq!(Ok(Level::Warn), Level::from_str("2"));
+//!   assert!(Level::from_str("3").is_err());
+//!   ```
+//!
+//! - `#[strum(prefix = "...")]` and `#[strum(suffix = "...")]` are added to the name of every variant by
+//!   `Display`, `AsRefStr` and `IntoStaticStr`. `EnumString` requires them on its input so that
+//!   converting a variant to a string and back is lossless. Add `#[strum(optional_affixes)]` to also accept
+//!   the bare variant names.
+//!
+//! - `#[strum(to_string_template = "...")]`: The format `Display` writes variants with when they have no
+//!   `to_string` of their own, such as `"{__variant}: {0}"`. Transparent and default variants keep writing
+//!   their field. `AsRefStr`, `IntoStaticStr` and `EnumString` still use the variant's name.
+//!
+//! - `#[strum(strict_locales)]` makes `Localized` reject the enum at compile time when a variant is
+//!   missing a `to_string(locale = "...")`, or a variant with a message a `message(locale = "...")`,
+//!   for any locale used elsewhere in the enum.
+//!
 //! ## Attributes on Variants
 //!
 //! Custom attributes are applied to a variant by adding `#[strum(parameter="value")]` to the variant.
 //!
 //! - `serialize="..."`: Changes the text that `FromStr()` looks for when parsing a string. This attribute can
-//!    be applied multiple times to an element and the enum variant will be parsed if any of them match.
+//!   be applied multiple times to an element and the enum variant will be parsed if any of them match.
+//!
+//! - `deprecated_alias="..."`: An old name the variant is still parsed from by `FromStr()`. Unlike `serialize`,
+//!   it is left out of `VariantNames`, `EnumMessage::get_serializations`, `to_string` and parse errors. With
+//!   `#[strum(parse_with_info)]` on the enum, the derived `parse_with_info` reports which alias matched, so old
+//!   clients can be found before it is removed.
+//!
+//! - `pattern="..."`: Parses the variant from every string matching a glob, where `?` stands for any one
+//!   character and `*` for any run of characters. Patterns are only tried once no exact serialization
+//!   matched, in declaration order. Of the variant's options, only `ascii_case_insensitive` applies to
+//!   them. Other fields are filled in the same way as for `serialize`. Can be applied multiple times.
+//!   Patterns don't replace the variant's name: without a `serialize`, it still parses from the name
+//!   `Display` writes. Patterns aren't listed in `VariantNotFound::expected` or offered as suggestions.
 //!
 //! - `to_string="..."`: Similar to `serialize`. This value will be included when using `FromStr()`. More importantly,
-//!    this specifies what text to use when calling `variant.to_string()` with the `Display` derivation, or when calling `variant.as_ref()` with `AsRefStr`.
+//!   this specifies what text to use when calling `variant.to_string()` with the `Display` derivation, or when calling `variant.as_ref()` with `AsRefStr`.
+//!   For `Display`, placeholders can name a field (`{0}`, `{name}`), a path into it (`{0.code}`), or one of
+//!   `{__variant}`, `{__message}` and `{__discriminant}`, each with an optional format spec. The other
+//!   derives would return the reserved placeholders unexpanded, so they reject them.
+//!
+//! - `to_string_alt="..."`: The text `Display` writes when formatted with `{:#}`, such as a longer
+//!   human readable label. Fields are interpolated the same way as in `to_string`, but the enum's
+//!   `prefix` and `suffix` aren't added.
+//!
+//! - `to_string(locale="...")="..."`: The label `Localized::to_localized` returns for that locale. Can be
+//!   applied once per locale; the variant's plain `to_string` is still what `Display` writes.
 //!
 //! - `default`: Applied to a single variant of an enum. The variant must be a Tuple-like
-//!    variant with a single piece of data that can be create from a `&str` i.e. `T: From<&str>`.
-//!    The generated code will now return the variant with the input string captured as shown below
-//!    instead of failing.
-//!
-//!     ```text
-//!     // Replaces this:
-//!     _ => Err(strum::ParseError::VariantNotFound)
-//!     // With this in generated code:
-//!     default => Ok(Variant(default.into()))
-//!     ```
-//!     The plugin will fail if the data doesn't implement From<&str>. You can only have one `default`
-//!     on your enum.
+//!   variant with a single piece of data that can be create from a `&str` i.e. `T: From<&str>`.
+//!   The generated code will now return the variant with the input string captured as shown below
+//!   instead of failing.
+//!
+//!   ```text
+//!   // Replaces this:
+//!   _ => Err(strum::ParseError::VariantNotFound(..))
+//!   // With this in generated code:
+//!   default => Ok(Variant(default.into()))
+//!   ```
+//!   The plugin will fail if the data doesn't implement From<&str>. You can only have one `default`
+//!   on your enum.
+//!
+//!   The data may also borrow from the input, as in `Ident(&'a str)` or `Ident(Cow<'a, str>)`, if
+//!   the enum has a single lifetime parameter. `FromStr` can't express that, so such enums get
+//!   `TryFrom<&'a str>` instead of `FromStr`, and can't use `extra_try_from` or `parse_list`.
+//!
+//!   ```rust
+//!   use std::convert::TryFrom;
+//!
+//!   #[derive(Debug, PartialEq, strum_macros::EnumString)]
+//!   #[strum(serialize_all = "lowercase")]
+//!   enum Token<'a> {
+//!       Let,
+//!       #[strum(default)]
+//!       Ident(&'a str),
+//!   }
+//!
+//!   let source = String::from("counter");
+//!   assert_eq!(Ok(Token::Ident("counter")), Token::try_from(source.as_str()));
+//!   assert_eq!(Ok(Token::Let), Token::try_from("let"));
+//!   ```
+//!
+//! - `default_from_str`: like `default`, but the input is parsed with the field's `FromStr` instead
+//!   of converted with `From<&str>`, so the catch-all can hold a number or any other parsed type.
+//!   If that parse fails, the error is a `ParseError::InvalidField` whose `reason` is the field's
+//!   error message. Enums with `parse_err_ty` need `From` implemented from the field's error.
+//!
+//!   ```rust
+//!   #[derive(Debug, PartialEq, strum_macros::EnumString, strum_macros::Display)]
+//!   enum Port {
+//!       #[strum(serialize = "http")]
+//!       Http,
+//!       #[strum(default_from_str)]
+//!       Number(u16),
+//!   }
+//!
+//!   assert_eq!(Ok(Port::Number(8080)), "8080".parse());
+//!   assert_eq!("8080", Port::Number(8080).to_string());
+//!   assert!("https".parse::<Port>().is_err());
+//!   ```
 //!
 //! - `transparent`: Signals that the inner field's implementation should be used, instead of generating
-//!    one for this variant. Only applicable to enum variants with a single field. Compatible with the
-//!    `AsRefStr`, `Display` and `IntoStaticStr` derive macros. Note that `IntoStaticStr` has a few restrictions,
-//!    the value must be `'static` and `const_into_str` is not supported in combination with `transparent` b/c
-//!    transparent relies on a call on `From::from(variant)`.
+//!   one for this variant. Only applicable to enum variants with a single field. Compatible with the
+//!   `AsRefStr`, `Display` and `IntoStaticStr` derive macros. Note that `IntoStaticStr` has a few restrictions,
+//!   the value must be `'static` and `const_into_str` is not supported in combination with `transparent` b/c
+//!   transparent relies on a call on `From::from(variant)`.
+//!
+//! - `parse_fields`: treats a `to_string` with placeholders such as `"Rgb({r}, {g}, {b})"` as a pattern
+//!   in `EnumString`. The text between the literal parts is parsed with each field's `FromStr`, so
+//!   `"Rgb(1, 2, 3)".parse()` round trips with `to_string()`. Fields missing from the pattern are
+//!   filled in with their defaults. Placeholders must be separated by some text and can't have a format
+//!   spec such as `{0:>5}`, since the padding couldn't be parsed back. Patterns aren't listed as
+//!   expected strings in `VariantNotFound`, and are tried in declaration order after all the exact
+//!   matches. If a field fails to parse, the error is
+//!   `ParseError::InvalidField`, which names the variant and the field.
 //!
 //! - `disabled`: removes variant from generated code.
 //!
@@ -86,12 +264,29 @@
 //!   If the whole enum is marked `ascii_case_insensitive`, you can specify `ascii_case_insensitive = false`
 //!   to disable case insensitivity on this variant.
 //!
+//! - `case_insensitive`: makes the comparison to this variant case insensitive using Unicode full case
+//!   folding, so `"STRASSE"` matches `"Straße"` and `"σοφος"` matches `"ΣΟΦΟΣ"`. Like `ascii_case_insensitive`, it can be turned off on a variant with
+//!   `case_insensitive = false`.
+//!
+//! - `trim`: ignores whitespace around the input when comparing it to this variant. Can be turned off with
+//!   `trim = false`.
+//!
+//! - `separator_insensitive`: treats `-`, `_`, a space and no separator at all as the same when comparing the
+//!   input to this variant, so `"dark-blue"`, `"dark_blue"` and `"dark blue"` all match `"darkblue"`.
+//!   Can be turned off with `separator_insensitive = false`.
+//!
+//!   These three options are applied to the serializations when the macro runs. At runtime the input is
+//!   normalized once into a stack buffer as long as the longest serialization, so they work without `std`.
+//!
 //! - `message=".."`: Adds a message to enum variant. This is used in conjunction with the `EnumMessage`
-//!    trait to associate a message with a variant. If `detailed_message` is not provided,
-//!    then `message` will also be returned when `get_detailed_message` is called.
+//!   trait to associate a message with a variant. If `detailed_message` is not provided,
+//!   then `message` will also be returned when `get_detailed_message` is called.
+//!
+//! - `message(locale="..")="..."`: The message `Localized::get_localized_message` returns for that locale,
+//!   falling back to `message`.
 //!
 //! - `detailed_message=".."`: Adds a more detailed message to a variant. If this value is omitted, then
-//!    `message` will be used in it's place.
+//!   `message` will be used in it's place.
 //!
 //! - Structured documentation, as in `/// ...`: If using `EnumMessage`, is accessible via get_documentation().
 //!
@@ -0,0 +1,84 @@
+//! Edit distance used to rank "did you mean" suggestions for `EnumString`.
+//!
+//! Everything here works on fixed size buffers so suggestions are available without `alloc`.
+//! The derive macro knows the longest serialization of the enum, so it picks `N` for us.
+
+/// Optimal string alignment distance between `input` and `candidate`. Insertions, deletions,
+/// substitutions and transpositions of adjacent characters each cost one. Characters that only
+/// differ by ASCII case are considered equal.
+///
+/// Returns `None` if `candidate` doesn't fit in the buffers or the distance exceeds `limit`.
+pub(crate) fn distance<const N: usize>(
+    input: &str,
+    candidate: &str,
+    limit: usize,
+) -> Option<usize> {
+    let mut cand = ['\0'; N];
+    let mut len = 0;
+    for c in candidate.chars() {
+        if len + 1 >= N {
+            return None;
+        }
+
+        cand[len] = c;
+        len += 1;
+    }
+
+    let mut prev2 = [0usize; N];
+    let mut prev = [0usize; N];
+    let mut cur = [0usize; N];
+    for (j, slot) in prev.iter_mut().enumerate().take(len + 1) {
+        *slot = j;
+    }
+
+    let mut last = None;
+    for (i, a) in input.chars().enumerate() {
+        cur[0] = i + 1;
+        let mut row_min = cur[0];
+        for j in 1..=len {
+            let b = cand[j - 1];
+            let cost = usize::from(!same(a, b));
+            let mut d = (prev[j] + 1).min(cur[j - 1] + 1).min(prev[j - 1] + cost);
+            if let Some(last) = last {
+                if j > 1 && same(a, cand[j - 2]) && same(last, b) {
+                    d = d.min(prev2[j - 2] + 1);
+                }
+            }
+
+            cur[j] = d;
+            row_min = row_min.min(d);
+        }
+
+        // Every later row is at least as large as the smallest entry of this one.
+        if row_min > limit {
+            return None;
+        }
+
+        prev2 = prev;
+        prev = cur;
+        last = Some(a);
+    }
+
+    Some(prev[len]).filter(|d| *d <= limit)
+}
+
+fn same(a: char, b: char) -> bool {
+    a.eq_ignore_ascii_case(&b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::distance;
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(Some(0), distance::<8>("Red", "Red", 3));
+        assert_eq!(Some(0), distance::<8>("red", "RED", 3));
+        assert_eq!(Some(1), distance::<8>("Rde", "Red", 3));
+        assert_eq!(Some(1), distance::<8>("Gren", "Green", 3));
+        assert_eq!(Some(1), distance::<8>("Greeen", "Green", 3));
+        assert_eq!(Some(3), distance::<8>("", "Red", 3));
+        assert_eq!(None, distance::<8>("Yellow", "Red", 3));
+        assert_eq!(None, distance::<4>("Green", "Green", 3));
+    }
+}
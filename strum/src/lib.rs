@@ -29,27 +29,40 @@
 
 // only for documentation purposes
 pub mod additional_attributes;
+mod edit_distance;
+mod parse_list;
+
+#[doc(hidden)]
+pub mod _private;
 
 use core::iter::FusedIterator;
 
+pub use parse_list::ParseList;
+
 #[cfg(feature = "phf")]
 #[doc(hidden)]
 pub use phf as _private_phf_reexport_for_macro_if_phf_feature;
 
 /// The `ParseError` enum is a collection of all the possible reasons
 /// an enum can fail to parse from a string.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum ParseError {
-    VariantNotFound,
+    VariantNotFound(VariantNotFound),
+    InvalidField(InvalidField),
+    Ambiguous(Ambiguous),
+    Duplicate(Duplicate),
 }
 
-#[cfg(feature = "std")]
-impl std::fmt::Display for ParseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         // We could use our macro here, but this way we don't take a dependency on the
         // macros crate.
         match self {
-            ParseError::VariantNotFound => write!(f, "Matching variant not found"),
+            ParseError::VariantNotFound(inner) => core::fmt::Display::fmt(inner, f),
+            ParseError::InvalidField(inner) => core::fmt::Display::fmt(inner, f),
+            ParseError::Ambiguous(inner) => core::fmt::Display::fmt(inner, f),
+            ParseError::Duplicate(inner) => core::fmt::Display::fmt(inner, f),
         }
     }
 }
@@ -58,10 +71,610 @@ impl std::fmt::Display for ParseError {
 impl std::error::Error for ParseError {
     fn description(&self) -> &str {
         match self {
-            ParseError::VariantNotFound => {
+            ParseError::VariantNotFound(..) => {
                 "Unable to find a variant of the given enum matching the string given. Matching \
                  can be extended with the Serialize attribute and is case sensitive."
             }
+            ParseError::InvalidField(..) => {
+                "The string matched the format of a variant, but one of the fields in it \
+                 failed to parse."
+            }
+            ParseError::Ambiguous(..) => {
+                "The string is an abbreviation of more than one variant of the given enum."
+            }
+            ParseError::Duplicate(..) => {
+                "The same variant appeared more than once in a list that rejects duplicates."
+            }
+        }
+    }
+}
+
+/// `core::error::Error` was stabilized in rust 1.81, above the crate's MSRV, so `no_std` users opt
+/// in with the `error_in_core` feature.
+#[cfg(all(not(feature = "std"), feature = "error_in_core"))]
+impl core::error::Error for ParseError {}
+
+/// The details of a string that didn't match any variant of an enum.
+///
+/// The derived `FromStr` records the rejected input along with every string the enum
+/// would have accepted. Without the `std` feature there is nowhere to copy the input to,
+/// so only its length is kept.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use strum::{EnumString, ParseError};
+///
+/// #[derive(Debug, EnumString)]
+/// enum Color {
+///     Red,
+///     #[strum(serialize = "b", serialize = "blue")]
+///     Blue,
+/// }
+///
+/// let ParseError::VariantNotFound(err) = Color::from_str("green").unwrap_err() else {
+///     unreachable!()
+/// };
+/// assert_eq!("green", err.input());
+/// assert_eq!(["Red", "b", "blue"], err.expected());
+/// assert_eq!(
+///     r#"Matching variant not found for "green", expected one of: "Red", "b", "blue""#,
+///     err.to_string()
+/// );
+/// ```
+///
+/// Enums marked `#[strum(suggest)]` also rank the accepted strings by their edit distance to
+/// the input, so typos can be answered with a "did you mean ...?".
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use strum::{EnumString, ParseError};
+///
+/// #[derive(Debug, EnumString)]
+/// #[strum(suggest)]
+/// enum Color {
+///     Red,
+///     Green,
+///     #[strum(serialize = "teal")]
+///     Teal,
+/// }
+///
+/// let ParseError::VariantNotFound(err) = Color::from_str("Gren").unwrap_err() else {
+///     unreachable!()
+/// };
+/// assert_eq!(["Green"], err.suggestions());
+/// assert_eq!(
+///     r#"Matching variant not found for "Gren", expected one of: "Red", "Green", "teal"; did you mean "Green"?"#,
+///     err.to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct VariantNotFound {
+    #[cfg(feature = "std")]
+    input: String,
+    input_len: usize,
+    expected: &'static [&'static str],
+    suggestions: [&'static str; MAX_SUGGESTIONS],
+    suggestion_count: usize,
+}
+
+const MAX_SUGGESTIONS: usize = 3;
+
+impl VariantNotFound {
+    /// Records that `input` matched none of the strings in `expected`, without any suggestions.
+    pub fn new(input: &str, expected: &'static [&'static str]) -> Self {
+        VariantNotFound {
+            #[cfg(feature = "std")]
+            input: input.into(),
+            input_len: input.len(),
+            expected,
+            suggestions: [""; MAX_SUGGESTIONS],
+            suggestion_count: 0,
+        }
+    }
+
+    /// Like [`VariantNotFound::new`], but also keeps the entries of `expected` closest to the
+    /// input. At most three suggestions are kept, all at the same distance, in declaration order.
+    ///
+    /// `N` must be greater than the number of chars in the longest entry of `expected`; longer
+    /// entries are never suggested. Differences in ASCII case don't count towards the distance.
+    pub fn with_suggestions<const N: usize>(
+        input: &str,
+        expected: &'static [&'static str],
+    ) -> Self {
+        let (suggestions, suggestion_count) = suggest::<N>(input, expected);
+        VariantNotFound {
+            suggestions,
+            suggestion_count,
+            ..Self::new(input, expected)
+        }
+    }
+
+    /// The string that failed to parse.
+    #[cfg(feature = "std")]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The length in bytes of the string that failed to parse.
+    pub fn input_len(&self) -> usize {
+        self.input_len
+    }
+
+    /// Every serialization the enum accepts, in declaration order.
+    pub fn expected(&self) -> &'static [&'static str] {
+        self.expected
+    }
+
+    /// The accepted strings closest to the input. Always empty unless the enum is marked
+    /// `#[strum(suggest)]`.
+    pub fn suggestions(&self) -> &[&'static str] {
+        &self.suggestions[..self.suggestion_count]
+    }
+}
+
+/// Keeps the entries of `expected` closest to `input`, see [`VariantNotFound::with_suggestions`].
+fn suggest<const N: usize>(
+    input: &str,
+    expected: &'static [&'static str],
+) -> ([&'static str; MAX_SUGGESTIONS], usize) {
+    let mut suggestions = [""; MAX_SUGGESTIONS];
+    let mut count = 0;
+
+    // Allow roughly one edit for every three characters of input.
+    let mut limit = input.chars().count().max(3) / 3;
+    for candidate in expected {
+        if suggestions[..count].contains(candidate) {
+            continue;
+        }
+
+        let distance = match edit_distance::distance::<N>(input, candidate, limit) {
+            Some(distance) => distance,
+            None => continue,
+        };
+
+        if distance < limit || count == 0 {
+            limit = distance;
+            count = 0;
+        }
+
+        if count < MAX_SUGGESTIONS {
+            suggestions[count] = candidate;
+            count += 1;
+        }
+    }
+
+    (suggestions, count)
+}
+
+impl core::fmt::Display for VariantNotFound {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "Matching variant not found")?;
+
+        #[cfg(feature = "std")]
+        write!(f, " for {:?}", self.input)?;
+
+        for (i, expected) in self.expected.iter().enumerate() {
+            let sep = if i == 0 { ", expected one of: " } else { ", " };
+            write!(f, "{}{:?}", sep, expected)?;
+        }
+
+        for (i, suggestion) in self.suggestions().iter().enumerate() {
+            let sep = match i {
+                0 => "; did you mean ",
+                _ if i + 1 == self.suggestion_count => " or ",
+                _ => ", ",
+            };
+            write!(f, "{}{:?}", sep, suggestion)?;
+        }
+
+        if self.suggestion_count > 0 {
+            write!(f, "?")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The details of a field that failed to parse. This is returned when the input matches the
+/// `to_string` format of a variant marked `#[strum(parse_fields)]`, but the text captured for
+/// one of its fields is rejected by the field's `FromStr`.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use strum::{EnumString, ParseError};
+///
+/// #[derive(Debug, PartialEq, EnumString)]
+/// #[strum(parse_fields)]
+/// enum Color {
+///     #[strum(to_string = "Rgb({r}, {g}, {b})")]
+///     Rgb { r: u8, g: u8, b: u8 },
+///     #[strum(to_string = "Gray({0})")]
+///     Gray(u8),
+/// }
+///
+/// assert_eq!(Color::Rgb { r: 1, g: 2, b: 3 }, Color::from_str("Rgb(1, 2, 3)").unwrap());
+/// assert_eq!(Color::Gray(128), Color::from_str("Gray(128)").unwrap());
+///
+/// let ParseError::InvalidField(err) = Color::from_str("Rgb(1, 256, 3)").unwrap_err() else {
+///     unreachable!()
+/// };
+/// assert_eq!("Rgb", err.variant());
+/// assert_eq!("g", err.field());
+/// assert_eq!("256", err.input());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct InvalidField {
+    #[cfg(feature = "std")]
+    input: String,
+    #[cfg(feature = "std")]
+    reason: Option<String>,
+    variant: &'static str,
+    field: &'static str,
+}
+
+impl InvalidField {
+    /// Records that `input` matched the format of `variant`, but `field` failed to parse from it.
+    pub fn new(input: &str, variant: &'static str, field: &'static str) -> Self {
+        #[cfg(not(feature = "std"))]
+        let _ = input;
+
+        InvalidField {
+            #[cfg(feature = "std")]
+            input: input.into(),
+            #[cfg(feature = "std")]
+            reason: None,
+            variant,
+            field,
+        }
+    }
+
+    /// Like [`InvalidField::new`], but also keeps the message of the error the field's `FromStr`
+    /// returned. The message is only kept with the `std` feature.
+    pub fn with_reason(
+        input: &str,
+        variant: &'static str,
+        field: &'static str,
+        reason: &dyn core::fmt::Display,
+    ) -> Self {
+        #[cfg(not(feature = "std"))]
+        let _ = reason;
+
+        InvalidField {
+            #[cfg(feature = "std")]
+            reason: Some(reason.to_string()),
+            ..Self::new(input, variant, field)
+        }
+    }
+
+    /// The text that was captured for the field.
+    #[cfg(feature = "std")]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The message of the error returned by the field's `FromStr`, if it was kept.
+    #[cfg(feature = "std")]
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// The name of the variant the input matched.
+    pub fn variant(&self) -> &'static str {
+        self.variant
+    }
+
+    /// The name of the field that failed to parse, or its index for tuple variants.
+    pub fn field(&self) -> &'static str {
+        self.field
+    }
+}
+
+impl core::fmt::Display for InvalidField {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "Invalid value")?;
+
+        #[cfg(feature = "std")]
+        write!(f, " {:?}", self.input)?;
+
+        write!(
+            f,
+            " for field `{}` of variant `{}`",
+            self.field, self.variant
+        )?;
+
+        #[cfg(feature = "std")]
+        if let Some(reason) = &self.reason {
+            write!(f, ": {}", reason)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The details of an abbreviation that could stand for more than one variant. This is returned
+/// by enums marked `#[strum(unique_prefix)]` when the input is the start of several of their
+/// serializations, but none of them in full.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use strum::{EnumString, ParseError};
+///
+/// #[derive(Debug, PartialEq, EnumString)]
+/// #[strum(serialize_all = "lowercase", unique_prefix)]
+/// enum Command {
+///     Stash,
+///     Status,
+///     Commit,
+/// }
+///
+/// assert_eq!(Command::Status, Command::from_str("stat").unwrap());
+/// assert_eq!(Command::Commit, Command::from_str("c").unwrap());
+///
+/// let ParseError::Ambiguous(err) = Command::from_str("st").unwrap_err() else {
+///     unreachable!()
+/// };
+/// assert_eq!("st", err.input());
+/// assert_eq!(["stash", "status"], err.candidates());
+/// assert_eq!(
+///     r#"Ambiguous abbreviation "st", could be one of: "stash", "status""#,
+///     err.to_string()
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Ambiguous {
+    #[cfg(feature = "std")]
+    input: String,
+    input_len: usize,
+    candidates: &'static [&'static str],
+}
+
+impl Ambiguous {
+    /// Records that `input` is an abbreviation of every string in `candidates`.
+    pub fn new(input: &str, candidates: &'static [&'static str]) -> Self {
+        Ambiguous {
+            #[cfg(feature = "std")]
+            input: input.into(),
+            input_len: input.len(),
+            candidates,
+        }
+    }
+
+    /// The abbreviation that failed to parse.
+    #[cfg(feature = "std")]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The length in bytes of the abbreviation that failed to parse.
+    pub fn input_len(&self) -> usize {
+        self.input_len
+    }
+
+    /// The serializations starting with the input, in lexicographic order.
+    pub fn candidates(&self) -> &'static [&'static str] {
+        self.candidates
+    }
+}
+
+impl core::fmt::Display for Ambiguous {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "Ambiguous abbreviation")?;
+
+        #[cfg(feature = "std")]
+        write!(f, " {:?}", self.input)?;
+
+        for (i, candidate) in self.candidates.iter().enumerate() {
+            let sep = if i == 0 { ", could be one of: " } else { ", " };
+            write!(f, "{}{:?}", sep, candidate)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An item of a list that parsed to the same value as an earlier item. This is returned by
+/// [`ParseList::reject_duplicates`].
+///
+/// ```rust
+/// use strum::{EnumString, ParseError};
+///
+/// #[derive(Debug, PartialEq, EnumString)]
+/// #[strum(serialize_all = "lowercase", parse_list)]
+/// enum Permission {
+///     Read,
+///     #[strum(serialize = "write", serialize = "w")]
+///     Write,
+/// }
+///
+/// let mut list = Permission::parse_list("read,write,w", ",").reject_duplicates();
+/// assert_eq!(Some(Ok(Permission::Read)), list.next());
+/// assert_eq!(Some(Ok(Permission::Write)), list.next());
+///
+/// let Some(Err(ParseError::Duplicate(err))) = list.next() else {
+///     unreachable!()
+/// };
+/// assert_eq!("w", err.input());
+/// assert_eq!(r#"Duplicate item "w" in list"#, err.to_string());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Duplicate {
+    #[cfg(feature = "std")]
+    input: String,
+    input_len: usize,
+}
+
+impl Duplicate {
+    /// Records that `input` appeared more than once in a list.
+    pub fn new(input: &str) -> Self {
+        Duplicate {
+            #[cfg(feature = "std")]
+            input: input.into(),
+            input_len: input.len(),
+        }
+    }
+
+    /// The repeated item, as it was written.
+    #[cfg(feature = "std")]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The length in bytes of the repeated item.
+    pub fn input_len(&self) -> usize {
+        self.input_len
+    }
+}
+
+impl core::fmt::Display for Duplicate {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "Duplicate item")?;
+
+        #[cfg(feature = "std")]
+        write!(f, " {:?}", self.input)?;
+
+        write!(f, " in list")
+    }
+}
+
+/// How the `parse_with_info` derived for `#[strum(parse_with_info)]` `EnumString` enums parsed its
+/// input.
+///
+/// ```rust
+/// use strum::EnumString;
+///
+/// #[derive(Debug, PartialEq, EnumString)]
+/// #[strum(parse_with_info)]
+/// enum Unit {
+///     #[strum(serialize = "meter", deprecated_alias = "metre")]
+///     Meter,
+/// }
+///
+/// let (unit, info) = Unit::parse_with_info("meter").unwrap();
+/// assert_eq!(Unit::Meter, unit);
+/// assert_eq!(None, info.deprecated_alias());
+///
+/// let (unit, info) = Unit::parse_with_info("metre").unwrap();
+/// assert_eq!(Unit::Meter, unit);
+/// assert_eq!(Some("metre"), info.deprecated_alias());
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct ParseInfo {
+    deprecated_alias: Option<&'static str>,
+}
+
+impl ParseInfo {
+    /// Records the deprecated alias the input matched, if any.
+    pub fn new(deprecated_alias: Option<&'static str>) -> Self {
+        ParseInfo { deprecated_alias }
+    }
+
+    /// The deprecated alias the input matched, as written in the attribute.
+    pub fn deprecated_alias(&self) -> Option<&'static str> {
+        self.deprecated_alias
+    }
+
+    /// Whether the input only parsed through a deprecated alias.
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated_alias.is_some()
+    }
+}
+
+/// What a `parse_err_context_fn` gets to build its error from: the rejected input, the name of
+/// the enum and every string the enum would have accepted. This lets a single generic function
+/// produce detailed errors for many enums.
+///
+/// ```rust
+/// use std::str::FromStr;
+/// use strum::{EnumString, ParseErrorContext};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum ConfigError {
+///     UnknownValue { kind: &'static str, value: String, expected: &'static [&'static str] },
+/// }
+///
+/// fn unknown_value(ctx: ParseErrorContext<'_>) -> ConfigError {
+///     ConfigError::UnknownValue {
+///         kind: ctx.enum_name(),
+///         value: ctx.input().to_owned(),
+///         expected: ctx.expected(),
+///     }
+/// }
+///
+/// #[derive(Debug, EnumString)]
+/// #[strum(parse_err_ty = ConfigError, parse_err_context_fn = unknown_value)]
+/// enum Mode {
+///     Fast,
+///     Safe,
+/// }
+///
+/// assert_eq!(
+///     ConfigError::UnknownValue { kind: "Mode", value: "slow".into(), expected: &["Fast", "Safe"] },
+///     Mode::from_str("slow").unwrap_err()
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ParseErrorContext<'a> {
+    input: &'a str,
+    enum_name: &'static str,
+    expected: &'static [&'static str],
+    suggestions: [&'static str; MAX_SUGGESTIONS],
+    suggestion_count: usize,
+}
+
+impl<'a> ParseErrorContext<'a> {
+    /// The context for `input` failing to parse as `enum_name`, without any suggestions.
+    pub fn new(input: &'a str, enum_name: &'static str, expected: &'static [&'static str]) -> Self {
+        ParseErrorContext {
+            input,
+            enum_name,
+            expected,
+            suggestions: [""; MAX_SUGGESTIONS],
+            suggestion_count: 0,
+        }
+    }
+
+    /// Like [`ParseErrorContext::new`], but also ranks the entries of `expected` closest to the
+    /// input, the same way as [`VariantNotFound::with_suggestions`].
+    pub fn with_suggestions<const N: usize>(
+        input: &'a str,
+        enum_name: &'static str,
+        expected: &'static [&'static str],
+    ) -> Self {
+        let (suggestions, suggestion_count) = suggest::<N>(input, expected);
+        ParseErrorContext {
+            suggestions,
+            suggestion_count,
+            ..Self::new(input, enum_name, expected)
+        }
+    }
+
+    /// The string that failed to parse.
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    /// The name of the enum that was parsed.
+    pub fn enum_name(&self) -> &'static str {
+        self.enum_name
+    }
+
+    /// Every serialization the enum accepts, in declaration order.
+    pub fn expected(&self) -> &'static [&'static str] {
+        self.expected
+    }
+
+    /// The accepted strings closest to the input. Always empty unless the enum is marked
+    /// `#[strum(suggest)]`.
+    pub fn suggestions(&self) -> &[&'static str] {
+        &self.suggestions[..self.suggestion_count]
+    }
+
+    /// The [`VariantNotFound`] the derived `FromStr` would have returned without a custom error.
+    pub fn not_found(&self) -> VariantNotFound {
+        VariantNotFound {
+            suggestions: self.suggestions,
+            suggestion_count: self.suggestion_count,
+            ..VariantNotFound::new(self.input, self.expected)
         }
     }
 }
@@ -153,6 +766,44 @@ pub trait EnumMessage {
     fn get_serializations(&self) -> &'static [&'static str];
 }
 
+/// Translations of the labels and messages of an enum's variants. This can be derived with
+/// `Localized` by annotating the variants with `#[strum(to_string(locale = "...") = "...")]` and
+/// `#[strum(message(locale = "...") = "...")]`.
+///
+/// # Example
+///
+/// ```rust
+/// use strum::Localized;
+///
+/// #[derive(Localized)]
+/// enum Color {
+///     #[strum(to_string(locale = "fr") = "Rouge", to_string(locale = "de") = "Rot")]
+///     Red,
+///     #[strum(to_string(locale = "fr") = "Vert")]
+///     #[strum(message = "Go", message(locale = "fr") = "Allez")]
+///     Green,
+/// }
+///
+/// assert_eq!(["fr", "de"], Color::LOCALES);
+/// assert_eq!(Some("Rouge"), Color::Red.to_localized("fr"));
+/// // Missing translations fall back to the variant's own name.
+/// assert_eq!(Some("Green"), Color::Green.to_localized("de"));
+/// assert_eq!(Some("Allez"), Color::Green.get_localized_message("fr"));
+/// assert_eq!(Some("Go"), Color::Green.get_localized_message("de"));
+/// ```
+pub trait Localized {
+    /// Every locale at least one variant is translated to, in order of first appearance.
+    const LOCALES: &'static [&'static str];
+
+    /// The label of the variant in `locale`. Without a translation, this is the string
+    /// `Display` would write, or `None` if that isn't a fixed string, e.g. because it
+    /// interpolates fields.
+    fn to_localized(&self, locale: &str) -> Option<&'static str>;
+
+    /// The message of the variant in `locale`, falling back to its untranslated `message`.
+    fn get_localized_message(&self, locale: &str) -> Option<&'static str>;
+}
+
 /// `EnumProperty` is a trait that makes it possible to store additional information
 /// with enum variants. This trait is designed to be used with the macro of the same
 /// name in the `strum_macros` crate. Currently, the string, integer and bool literals
@@ -258,6 +909,8 @@ DocumentMacroRexports! {
     EnumMessage,
     EnumProperty,
     EnumString,
+    Localized,
+    StrumDebug,
     VariantNames,
     FromRepr,
     IntoStaticStr,
@@ -0,0 +1,110 @@
+use core::iter::FusedIterator;
+use core::str::FromStr;
+
+/// An iterator over the items of a delimited list, each parsed with `FromStr`. It is returned by
+/// the `parse_list` method that `EnumString` derives for `#[strum(parse_list)]` enums, so aliases
+/// and case insensitivity work the same as when parsing a single value. It doesn't allocate.
+///
+/// An empty string is an empty list. Items are not trimmed, so either include the whitespace in
+/// the separator or mark the enum `#[strum(trim)]`.
+///
+/// # Panics
+///
+/// `parse_list` and `collect_list` panic if the separator is empty.
+///
+/// ```rust
+/// use strum::EnumString;
+///
+/// #[derive(Debug, PartialEq, EnumString)]
+/// #[strum(serialize_all = "lowercase", ascii_case_insensitive, parse_list)]
+/// enum Permission {
+///     Read,
+///     Write,
+///     Admin,
+/// }
+///
+/// let permissions: Vec<_> = Permission::parse_list("read,WRITE,admin", ",")
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(vec![Permission::Read, Permission::Write, Permission::Admin], permissions);
+///
+/// let permissions: Result<Vec<Permission>, _> = Permission::collect_list("read; root", "; ");
+/// assert!(permissions.is_err());
+/// ```
+pub struct ParseList<'a, T: FromStr> {
+    rest: Option<&'a str>,
+    input: &'a str,
+    sep: &'a str,
+    reject_duplicates: bool,
+    same: fn(&T, &T) -> bool,
+    duplicate: fn(&str) -> T::Err,
+}
+
+impl<'a, T: FromStr> ParseList<'a, T> {
+    /// Called by the derived `parse_list`. `same` decides whether two items are duplicates and
+    /// `duplicate` builds the error for the second one.
+    #[doc(hidden)]
+    pub fn new(
+        input: &'a str,
+        sep: &'a str,
+        same: fn(&T, &T) -> bool,
+        duplicate: fn(&str) -> T::Err,
+    ) -> Self {
+        assert!(!sep.is_empty(), "the separator of a list can't be empty");
+
+        ParseList {
+            rest: Some(input).filter(|input| !input.is_empty()),
+            input,
+            sep,
+            reject_duplicates: false,
+            same,
+            duplicate,
+        }
+    }
+
+    /// Makes an item that parses to the same variant as an earlier one an error. For the
+    /// `default` variant, the captured strings are compared instead.
+    ///
+    /// The earlier items are parsed again instead of being stored, so checking a list of `n`
+    /// items takes `O(n²)` parses. That's meant for short lists such as flags or settings; collect
+    /// long ones into a set instead.
+    pub fn reject_duplicates(mut self) -> Self {
+        self.reject_duplicates = true;
+        self
+    }
+}
+
+impl<'a, T: FromStr> Iterator for ParseList<'a, T> {
+    type Item = Result<T, T::Err>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest?;
+        let (item, rest) = match rest.split_once(self.sep) {
+            Some((item, rest)) => (item, Some(rest)),
+            None => (rest, None),
+        };
+
+        let parsed_len = self.input.len() - self.rest.map_or(0, str::len);
+        self.rest = rest;
+
+        let value = match T::from_str(item) {
+            Ok(value) => value,
+            Err(err) => return Some(Err(err)),
+        };
+
+        if self.reject_duplicates && parsed_len > 0 {
+            let earlier = &self.input[..parsed_len - self.sep.len()];
+            for earlier in earlier.split(self.sep) {
+                if let Ok(earlier) = T::from_str(earlier) {
+                    if (self.same)(&earlier, &value) {
+                        return Some(Err((self.duplicate)(item)));
+                    }
+                }
+            }
+        }
+
+        Some(Ok(value))
+    }
+}
+
+impl<'a, T: FromStr> FusedIterator for ParseList<'a, T> {}
@@ -80,6 +80,23 @@ impl FromStr for CaseStyle {
     }
 }
 
+impl CaseStyle {
+    /// Every case style, in the order `parse_any_case` tries them.
+    pub const ALL: &'static [CaseStyle] = &[
+        CaseStyle::CamelCase,
+        CaseStyle::PascalCase,
+        CaseStyle::KebabCase,
+        CaseStyle::SnakeCase,
+        CaseStyle::ShoutySnakeCase,
+        CaseStyle::ScreamingKebabCase,
+        CaseStyle::LowerCase,
+        CaseStyle::UpperCase,
+        CaseStyle::TitleCase,
+        CaseStyle::MixedCase,
+        CaseStyle::TrainCase,
+    ];
+}
+
 pub trait CaseStyleHelpers {
     fn convert_case(&self, case_style: Option<CaseStyle>) -> String;
 }
@@ -116,6 +133,23 @@ impl CaseStyleHelpers for Ident {
     }
 }
 
+/// heck doesn't treat numbers as new words, but this function does.
+/// E.g. for input `Hello2You`, heck would output `hello2_you`, and snakify would output `hello_2_you`.
+pub fn snakify(s: &str) -> String {
+    let mut output: Vec<char> = s.to_string().to_snake_case().chars().collect();
+    let mut num_starts = vec![];
+    for (pos, c) in output.iter().enumerate() {
+        if c.is_ascii_digit() && pos != 0 && !output[pos - 1].is_ascii_digit() {
+            num_starts.push(pos);
+        }
+    }
+    // need to do in reverse, because after inserting, all chars after the point of insertion are off
+    for i in num_starts.into_iter().rev() {
+        output.insert(i, '_')
+    }
+    output.into_iter().collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -159,20 +193,3 @@ mod tests {
         assert_eq!(MixedCase, f("mixed_case").unwrap());
     }
 }
-
-/// heck doesn't treat numbers as new words, but this function does.
-/// E.g. for input `Hello2You`, heck would output `hello2_you`, and snakify would output `hello_2_you`.
-pub fn snakify(s: &str) -> String {
-    let mut output: Vec<char> = s.to_string().to_snake_case().chars().collect();
-    let mut num_starts = vec![];
-    for (pos, c) in output.iter().enumerate() {
-        if c.is_digit(10) && pos != 0 && !output[pos - 1].is_digit(10) {
-            num_starts.push(pos);
-        }
-    }
-    // need to do in reverse, because after inserting, all chars after the point of insertion are off
-    for i in num_starts.into_iter().rev() {
-        output.insert(i, '_')
-    }
-    output.into_iter().collect()
-}
@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{LitByte, LitByteStr};
+
+/// A string the decision tree recognizes, and the expression it returns for it.
+pub struct Entry {
+    pub bytes: Vec<u8>,
+    pub ascii_case_insensitive: bool,
+    pub value: TokenStream,
+}
+
+impl Entry {
+    /// The values the byte at `pos` may take for this entry to match.
+    fn keys(&self, pos: usize) -> Vec<u8> {
+        let b = self.bytes[pos];
+        if self.ascii_case_insensitive && b.is_ascii_alphabetic() {
+            vec![b.to_ascii_lowercase(), b.to_ascii_uppercase()]
+        } else {
+            vec![b]
+        }
+    }
+}
+
+/// Generates statements that look `bytes` up among `entries` and `return` the value of the one
+/// that matches. The input is first dispatched on its length, then on whichever byte splits the
+/// remaining candidates best, so a lookup takes a handful of comparisons regardless of how many
+/// entries there are. Only the last candidate is compared in full.
+pub fn decision_tree(entries: &[Entry]) -> TokenStream {
+    let mut by_len: BTreeMap<usize, Vec<&Entry>> = BTreeMap::new();
+    for entry in entries {
+        by_len.entry(entry.bytes.len()).or_default().push(entry);
+    }
+
+    let arms = by_len.into_iter().map(|(len, entries)| {
+        let node = node(&entries, &mut vec![false; len]);
+        quote! { #len => #node }
+    });
+
+    quote! {
+        match bytes.len() {
+            #(#arms,)*
+            _ => {}
+        }
+    }
+}
+
+fn node(entries: &[&Entry], used: &mut [bool]) -> TokenStream {
+    // Pick the byte that leaves the fewest candidates in the largest branch.
+    let best = (0..used.len())
+        .filter(|pos| !used[*pos])
+        .map(|pos| (pos, branches(entries, pos)))
+        .min_by_key(|(_, branches)| branches.iter().map(|(_, b)| b.len()).max());
+
+    let (pos, branches) = match best {
+        Some((pos, branches))
+            if entries.len() > 1 && branches.iter().all(|(_, b)| b.len() < entries.len()) =>
+        {
+            (pos, branches)
+        }
+        _ => return leaf(entries),
+    };
+
+    used[pos] = true;
+    let arms = branches
+        .into_iter()
+        .map(|(keys, indices)| {
+            let keys = keys.into_iter().map(|k| LitByte::new(k, Span::call_site()));
+            let entries: Vec<&Entry> = indices.into_iter().map(|i| entries[i]).collect();
+            let node = node(&entries, used);
+            quote! { #(#keys)|* => #node }
+        })
+        .collect::<Vec<_>>();
+    used[pos] = false;
+
+    quote! {
+        match bytes[#pos] {
+            #(#arms,)*
+            _ => {}
+        }
+    }
+}
+
+/// Groups the entries by the byte at `pos`. Bytes leading to the same entries share a branch.
+fn branches(entries: &[&Entry], pos: usize) -> Vec<(Vec<u8>, Vec<usize>)> {
+    let mut by_key: BTreeMap<u8, Vec<usize>> = BTreeMap::new();
+    for (i, entry) in entries.iter().enumerate() {
+        for key in entry.keys(pos) {
+            by_key.entry(key).or_default().push(i);
+        }
+    }
+
+    let mut branches: Vec<(Vec<u8>, Vec<usize>)> = Vec::new();
+    for (key, indices) in by_key {
+        match branches.iter_mut().find(|(_, other)| *other == indices) {
+            Some((keys, _)) => keys.push(key),
+            None => branches.push((vec![key], indices)),
+        }
+    }
+
+    branches
+}
+
+fn leaf(entries: &[&Entry]) -> TokenStream {
+    let checks = entries.iter().map(|entry| {
+        let lit = LitByteStr::new(&entry.bytes, Span::call_site());
+        let value = &entry.value;
+        if entry.ascii_case_insensitive {
+            quote! { if bytes.eq_ignore_ascii_case(#lit) { return #value; } }
+        } else {
+            quote! { if bytes == #lit { return #value; } }
+        }
+    });
+
+    quote! { { #(#checks)* } }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(s: &str, ascii_case_insensitive: bool) -> Entry {
+        Entry {
+            bytes: s.as_bytes().to_vec(),
+            ascii_case_insensitive,
+            value: quote!(),
+        }
+    }
+
+    #[test]
+    fn test_branches() {
+        let a = entry("cat", false);
+        let b = entry("car", false);
+        let c = entry("CAN", true);
+        let entries = [&a, &b, &c];
+
+        assert_eq!(
+            vec![(vec![b'C'], vec![2]), (vec![b'c'], vec![0, 1, 2])],
+            branches(&entries, 0)
+        );
+        assert_eq!(
+            vec![
+                (vec![b'N', b'n'], vec![2]),
+                (vec![b'r'], vec![1]),
+                (vec![b't'], vec![0]),
+            ],
+            branches(&entries, 2)
+        );
+    }
+}
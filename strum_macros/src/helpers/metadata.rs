@@ -17,11 +17,27 @@ pub mod kw {
     // enum metadata
     custom_keyword!(serialize_all);
     custom_keyword!(const_into_str);
+    custom_keyword!(const_from_str);
+    custom_keyword!(extra_try_from);
+    custom_keyword!(parse_list);
+    custom_keyword!(parse_with_info);
+    custom_keyword!(parse_borrowed);
+    custom_keyword!(parse_prefix);
     custom_keyword!(use_phf);
+    custom_keyword!(use_decision_tree);
     custom_keyword!(prefix);
     custom_keyword!(suffix);
     custom_keyword!(parse_err_ty);
     custom_keyword!(parse_err_fn);
+    custom_keyword!(parse_err_context_fn);
+    custom_keyword!(suggest);
+    custom_keyword!(parse_fields);
+    custom_keyword!(optional_affixes);
+    custom_keyword!(parse_any_case);
+    custom_keyword!(parse_case_styles);
+    custom_keyword!(unique_prefix);
+    custom_keyword!(parse_repr);
+    custom_keyword!(strict_locales);
 
     // enum discriminant metadata
     custom_keyword!(derive);
@@ -33,13 +49,22 @@ pub mod kw {
     custom_keyword!(message);
     custom_keyword!(detailed_message);
     custom_keyword!(serialize);
+    custom_keyword!(deprecated_alias);
+    custom_keyword!(pattern);
     custom_keyword!(to_string);
+    custom_keyword!(to_string_alt);
+    custom_keyword!(to_string_template);
+    custom_keyword!(locale);
     custom_keyword!(transparent);
     custom_keyword!(disabled);
     custom_keyword!(default);
     custom_keyword!(default_with);
+    custom_keyword!(default_from_str);
     custom_keyword!(props);
     custom_keyword!(ascii_case_insensitive);
+    custom_keyword!(case_insensitive);
+    custom_keyword!(trim);
+    custom_keyword!(separator_insensitive);
 }
 
 pub enum EnumMeta {
@@ -48,11 +73,15 @@ pub enum EnumMeta {
         case_style: CaseStyle,
     },
     AsciiCaseInsensitive(kw::ascii_case_insensitive),
+    CaseInsensitive(kw::case_insensitive),
+    Trim(kw::trim),
+    SeparatorInsensitive(kw::separator_insensitive),
     Crate {
         kw: kw::Crate,
         crate_module_path: Path,
     },
     UsePhf(kw::use_phf),
+    UseDecisionTree(kw::use_decision_tree),
     Prefix {
         kw: kw::prefix,
         prefix: LitStr,
@@ -69,7 +98,32 @@ pub enum EnumMeta {
         kw: kw::parse_err_fn,
         path: Path,
     },
+    ParseErrContextFn {
+        kw: kw::parse_err_context_fn,
+        path: Path,
+    },
     ConstIntoStr(kw::const_into_str),
+    ConstFromStr(kw::const_from_str),
+    ExtraTryFrom(kw::extra_try_from),
+    ParseList(kw::parse_list),
+    ParseWithInfo(kw::parse_with_info),
+    ParseBorrowed(kw::parse_borrowed),
+    ParsePrefix(kw::parse_prefix),
+    Suggest(kw::suggest),
+    ParseFields(kw::parse_fields),
+    OptionalAffixes(kw::optional_affixes),
+    ParseAnyCase(kw::parse_any_case),
+    ParseCaseStyles {
+        kw: kw::parse_case_styles,
+        case_styles: Vec<CaseStyle>,
+    },
+    UniquePrefix(kw::unique_prefix),
+    ParseRepr(kw::parse_repr),
+    StrictLocales(kw::strict_locales),
+    ToStringTemplate {
+        kw: kw::to_string_template,
+        template: LitStr,
+    },
 }
 
 impl Parse for EnumMeta {
@@ -92,8 +146,16 @@ impl Parse for EnumMeta {
             })
         } else if lookahead.peek(kw::ascii_case_insensitive) {
             Ok(EnumMeta::AsciiCaseInsensitive(input.parse()?))
+        } else if lookahead.peek(kw::case_insensitive) {
+            Ok(EnumMeta::CaseInsensitive(input.parse()?))
+        } else if lookahead.peek(kw::trim) {
+            Ok(EnumMeta::Trim(input.parse()?))
+        } else if lookahead.peek(kw::separator_insensitive) {
+            Ok(EnumMeta::SeparatorInsensitive(input.parse()?))
         } else if lookahead.peek(kw::use_phf) {
             Ok(EnumMeta::UsePhf(input.parse()?))
+        } else if lookahead.peek(kw::use_decision_tree) {
+            Ok(EnumMeta::UseDecisionTree(input.parse()?))
         } else if lookahead.peek(kw::prefix) {
             let kw = input.parse::<kw::prefix>()?;
             input.parse::<Token![=]>()?;
@@ -114,8 +176,53 @@ impl Parse for EnumMeta {
             input.parse::<Token![=]>()?;
             let path: Path = input.parse()?;
             Ok(EnumMeta::ParseErrFn { kw, path })
+        } else if lookahead.peek(kw::parse_err_context_fn) {
+            let kw = input.parse::<kw::parse_err_context_fn>()?;
+            input.parse::<Token![=]>()?;
+            let path: Path = input.parse()?;
+            Ok(EnumMeta::ParseErrContextFn { kw, path })
         } else if lookahead.peek(kw::const_into_str) {
             Ok(EnumMeta::ConstIntoStr(input.parse()?))
+        } else if lookahead.peek(kw::const_from_str) {
+            Ok(EnumMeta::ConstFromStr(input.parse()?))
+        } else if lookahead.peek(kw::extra_try_from) {
+            Ok(EnumMeta::ExtraTryFrom(input.parse()?))
+        } else if lookahead.peek(kw::parse_list) {
+            Ok(EnumMeta::ParseList(input.parse()?))
+        } else if lookahead.peek(kw::parse_with_info) {
+            Ok(EnumMeta::ParseWithInfo(input.parse()?))
+        } else if lookahead.peek(kw::parse_borrowed) {
+            Ok(EnumMeta::ParseBorrowed(input.parse()?))
+        } else if lookahead.peek(kw::parse_prefix) {
+            Ok(EnumMeta::ParsePrefix(input.parse()?))
+        } else if lookahead.peek(kw::suggest) {
+            Ok(EnumMeta::Suggest(input.parse()?))
+        } else if lookahead.peek(kw::parse_fields) {
+            Ok(EnumMeta::ParseFields(input.parse()?))
+        } else if lookahead.peek(kw::optional_affixes) {
+            Ok(EnumMeta::OptionalAffixes(input.parse()?))
+        } else if lookahead.peek(kw::parse_any_case) {
+            Ok(EnumMeta::ParseAnyCase(input.parse()?))
+        } else if lookahead.peek(kw::parse_case_styles) {
+            let kw = input.parse::<kw::parse_case_styles>()?;
+            let content;
+            parenthesized!(content in input);
+            let case_styles = content.parse_terminated(CaseStyle::parse, Token![,])?;
+            Ok(EnumMeta::ParseCaseStyles {
+                kw,
+                case_styles: case_styles.into_iter().collect(),
+            })
+        } else if lookahead.peek(kw::unique_prefix) {
+            Ok(EnumMeta::UniquePrefix(input.parse()?))
+        } else if lookahead.peek(kw::parse_repr) {
+            Ok(EnumMeta::ParseRepr(input.parse()?))
+        } else if lookahead.peek(kw::strict_locales) {
+            Ok(EnumMeta::StrictLocales(input.parse()?))
+        } else if lookahead.peek(kw::to_string_template) {
+            let kw = input.parse::<kw::to_string_template>()?;
+            input.parse::<Token![=]>()?;
+            let template = input.parse()?;
+            Ok(EnumMeta::ToStringTemplate { kw, template })
         } else {
             Err(lookahead.error())
         }
@@ -199,6 +306,14 @@ pub enum VariantMeta {
         _kw: kw::serialize,
         value: LitStr,
     },
+    DeprecatedAlias {
+        _kw: kw::deprecated_alias,
+        value: LitStr,
+    },
+    Pattern {
+        _kw: kw::pattern,
+        value: LitStr,
+    },
     Documentation {
         value: LitStr,
     },
@@ -206,9 +321,24 @@ pub enum VariantMeta {
         kw: kw::to_string,
         value: LitStr,
     },
+    ToStringAlt {
+        kw: kw::to_string_alt,
+        value: LitStr,
+    },
+    LocalizedToString {
+        _kw: kw::to_string,
+        locale: LitStr,
+        value: LitStr,
+    },
+    LocalizedMessage {
+        _kw: kw::message,
+        locale: LitStr,
+        value: LitStr,
+    },
     Transparent(kw::transparent),
     Disabled(kw::disabled),
     Default(kw::default),
+    DefaultFromStr(kw::default_from_str),
     DefaultWith {
         kw: kw::default_with,
         value: LitStr,
@@ -217,10 +347,23 @@ pub enum VariantMeta {
         kw: kw::ascii_case_insensitive,
         value: bool,
     },
+    CaseInsensitive {
+        kw: kw::case_insensitive,
+        value: bool,
+    },
+    Trim {
+        kw: kw::trim,
+        value: bool,
+    },
+    SeparatorInsensitive {
+        kw: kw::separator_insensitive,
+        value: bool,
+    },
     Props {
         _kw: kw::props,
         props: Vec<(LitStr, Lit)>,
     },
+    ParseFields(kw::parse_fields),
 }
 
 impl Parse for VariantMeta {
@@ -228,9 +371,17 @@ impl Parse for VariantMeta {
         let lookahead = input.lookahead1();
         if lookahead.peek(kw::message) {
             let kw = input.parse()?;
+            let locale = parse_optional_locale(input)?;
             let _: Token![=] = input.parse()?;
             let value = input.parse()?;
-            Ok(VariantMeta::Message { kw, value })
+            match locale {
+                Some(locale) => Ok(VariantMeta::LocalizedMessage {
+                    _kw: kw,
+                    locale,
+                    value,
+                }),
+                None => Ok(VariantMeta::Message { kw, value }),
+            }
         } else if lookahead.peek(kw::detailed_message) {
             let kw = input.parse()?;
             let _: Token![=] = input.parse()?;
@@ -241,17 +392,42 @@ impl Parse for VariantMeta {
             let _: Token![=] = input.parse()?;
             let value = input.parse()?;
             Ok(VariantMeta::Serialize { _kw, value })
+        } else if lookahead.peek(kw::deprecated_alias) {
+            let _kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let value = input.parse()?;
+            Ok(VariantMeta::DeprecatedAlias { _kw, value })
+        } else if lookahead.peek(kw::pattern) {
+            let _kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let value = input.parse()?;
+            Ok(VariantMeta::Pattern { _kw, value })
         } else if lookahead.peek(kw::to_string) {
+            let kw = input.parse()?;
+            let locale = parse_optional_locale(input)?;
+            let _: Token![=] = input.parse()?;
+            let value = input.parse()?;
+            match locale {
+                Some(locale) => Ok(VariantMeta::LocalizedToString {
+                    _kw: kw,
+                    locale,
+                    value,
+                }),
+                None => Ok(VariantMeta::ToString { kw, value }),
+            }
+        } else if lookahead.peek(kw::to_string_alt) {
             let kw = input.parse()?;
             let _: Token![=] = input.parse()?;
             let value = input.parse()?;
-            Ok(VariantMeta::ToString { kw, value })
+            Ok(VariantMeta::ToStringAlt { kw, value })
         } else if lookahead.peek(kw::transparent) {
             Ok(VariantMeta::Transparent(input.parse()?))
         } else if lookahead.peek(kw::disabled) {
             Ok(VariantMeta::Disabled(input.parse()?))
         } else if lookahead.peek(kw::default) {
             Ok(VariantMeta::Default(input.parse()?))
+        } else if lookahead.peek(kw::default_from_str) {
+            Ok(VariantMeta::DefaultFromStr(input.parse()?))
         } else if lookahead.peek(kw::default_with) {
             let kw = input.parse()?;
             let _: Token![=] = input.parse()?;
@@ -266,6 +442,18 @@ impl Parse for VariantMeta {
                 true
             };
             Ok(VariantMeta::AsciiCaseInsensitive { kw, value })
+        } else if lookahead.peek(kw::case_insensitive) {
+            let kw = input.parse()?;
+            let value = parse_optional_bool(input)?;
+            Ok(VariantMeta::CaseInsensitive { kw, value })
+        } else if lookahead.peek(kw::trim) {
+            let kw = input.parse()?;
+            let value = parse_optional_bool(input)?;
+            Ok(VariantMeta::Trim { kw, value })
+        } else if lookahead.peek(kw::separator_insensitive) {
+            let kw = input.parse()?;
+            let value = parse_optional_bool(input)?;
+            Ok(VariantMeta::SeparatorInsensitive { kw, value })
         } else if lookahead.peek(kw::props) {
             let _kw = input.parse()?;
             let content;
@@ -278,12 +466,37 @@ impl Parse for VariantMeta {
                     .map(|Prop(k, v)| (LitStr::new(&k.to_string(), k.span()), v))
                     .collect(),
             })
+        } else if lookahead.peek(kw::parse_fields) {
+            Ok(VariantMeta::ParseFields(input.parse()?))
         } else {
             Err(lookahead.error())
         }
     }
 }
 
+/// Parses the `(locale = "fr")` that may follow `to_string` or `message`.
+fn parse_optional_locale(input: ParseStream) -> syn::Result<Option<LitStr>> {
+    if !input.peek(syn::token::Paren) {
+        return Ok(None);
+    }
+
+    let content;
+    parenthesized!(content in input);
+    let _: kw::locale = content.parse()?;
+    let _: Token![=] = content.parse()?;
+    Ok(Some(content.parse()?))
+}
+
+/// Parses the `= true` or `= false` that may follow a flag. A bare flag means `true`.
+fn parse_optional_bool(input: ParseStream) -> syn::Result<bool> {
+    if input.peek(Token![=]) {
+        let _: Token![=] = input.parse()?;
+        Ok(input.parse::<LitBool>()?.value)
+    } else {
+        Ok(true)
+    }
+}
+
 struct Prop(Ident, Lit);
 
 impl Parse for Prop {
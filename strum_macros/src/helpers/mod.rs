@@ -4,8 +4,10 @@ pub use self::type_props::HasTypeProperties;
 pub use self::variant_props::HasStrumVariantProperties;
 
 pub mod case_style;
+pub mod decision_tree;
 pub mod inner_variant_props;
 mod metadata;
+pub mod template;
 pub mod type_props;
 pub mod variant_props;
 
@@ -16,7 +18,7 @@ use syn::spanned::Spanned;
 pub fn missing_parse_err_attr_error() -> syn::Error {
     syn::Error::new(
         Span::call_site(),
-        "`parse_err_ty` and `parse_err_fn` attributes are both required.",
+        "`parse_err_ty` and `parse_err_fn` (or `parse_err_context_fn`) attributes are both required.",
     )
 }
 
@@ -0,0 +1,115 @@
+use syn::LitStr;
+
+/// A piece of a `to_string` format string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Segment {
+    /// Text that is copied verbatim. Escaped braces are already unescaped.
+    Literal(String),
+    /// A `{name}` or `{0}` placeholder, split into the name and the format spec after the `:`.
+    Placeholder { name: String, spec: Option<String> },
+}
+
+/// Splits a format string like `"Rgb({r}, {g:>3}, {{b}})"` into literals and placeholders.
+pub fn parse_template(string_literal: &LitStr) -> syn::Result<Vec<Segment>> {
+    let value = string_literal.value();
+    let mut segments = Vec::new();
+    let mut literal = String::new();
+    let mut chars = value.chars().peekable();
+
+    while let Some(chr) = chars.next() {
+        match chr {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut inside = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some('{') | None => {
+                            return Err(syn::Error::new_spanned(
+                                string_literal,
+                                "Bracket opened without closing previous bracket",
+                            ))
+                        }
+                        Some(c) => inside.push(c),
+                    }
+                }
+
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                }
+
+                let (name, spec) = match inside.split_once(':') {
+                    Some((name, spec)) => (name, Some(spec.to_owned())),
+                    None => (inside.as_str(), None),
+                };
+                segments.push(Segment::Placeholder {
+                    name: name.trim_end().to_owned(),
+                    spec,
+                });
+            }
+            '}' => {
+                return Err(syn::Error::new_spanned(
+                    string_literal,
+                    "Bracket closed without previous opened bracket",
+                ))
+            }
+            c => literal.push(c),
+        }
+    }
+
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proc_macro2::Span;
+
+    fn parse(s: &str) -> syn::Result<Vec<Segment>> {
+        parse_template(&LitStr::new(s, Span::call_site()))
+    }
+
+    #[test]
+    fn test_parse_template() {
+        use Segment::*;
+
+        assert_eq!(
+            vec![
+                Literal("Rgb(".into()),
+                Placeholder {
+                    name: "r".into(),
+                    spec: None
+                },
+                Literal(", ".into()),
+                Placeholder {
+                    name: "0".into(),
+                    spec: Some(">3".into())
+                },
+                Literal(") {b}".into()),
+            ],
+            parse("Rgb({r}, {0:>3}) {{b}}").unwrap()
+        );
+        assert_eq!(vec![Literal("plain".into())], parse("plain").unwrap());
+        assert_eq!(
+            vec![Placeholder {
+                name: "inner.name".into(),
+                spec: Some("?".into())
+            }],
+            parse("{inner.name:?}").unwrap()
+        );
+        assert!(parse("{a").is_err());
+        assert!(parse("a}").is_err());
+        assert!(parse("{a{b}}").is_err());
+    }
+}
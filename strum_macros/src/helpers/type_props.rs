@@ -4,7 +4,7 @@ use std::default::Default;
 use syn::{parse_quote, DeriveInput, Ident, LitStr, Path, Visibility};
 
 use super::case_style::CaseStyle;
-use super::metadata::{DeriveInputExt, EnumDiscriminantsMeta, EnumMeta};
+use super::metadata::{kw, DeriveInputExt, EnumDiscriminantsMeta, EnumMeta};
 use super::occurrence_error;
 
 pub trait HasTypeProperties {
@@ -15,19 +15,38 @@ pub trait HasTypeProperties {
 pub struct StrumTypeProperties {
     pub parse_err_ty: Option<Path>,
     pub parse_err_fn: Option<Path>,
+    pub parse_err_context_fn: Option<Path>,
     pub case_style: Option<CaseStyle>,
     pub ascii_case_insensitive: bool,
+    pub case_insensitive: bool,
+    pub trim: bool,
+    pub separator_insensitive: bool,
     pub crate_module_path: Option<Path>,
     pub discriminant_derives: Vec<Path>,
     pub discriminant_name: Option<Ident>,
     pub discriminant_others: Vec<TokenStream>,
     pub discriminant_vis: Option<Visibility>,
     pub use_phf: bool,
+    pub use_decision_tree: bool,
     pub prefix: Option<LitStr>,
     pub suffix: Option<LitStr>,
     pub enum_repr: Option<TokenStream>,
     pub const_into_str: bool,
+    pub const_from_str: Option<kw::const_from_str>,
+    pub extra_try_from: Option<kw::extra_try_from>,
+    pub parse_list: Option<kw::parse_list>,
+    pub parse_with_info: bool,
+    pub parse_borrowed: Option<kw::parse_borrowed>,
+    pub parse_prefix: Option<kw::parse_prefix>,
     pub discriminant_docs: Vec<LitStr>,
+    pub suggest: Option<kw::suggest>,
+    pub parse_fields: bool,
+    pub optional_affixes: bool,
+    pub parse_case_styles: Vec<CaseStyle>,
+    pub unique_prefix: bool,
+    pub parse_repr: Option<kw::parse_repr>,
+    pub strict_locales: bool,
+    pub to_string_template: Option<LitStr>,
 }
 
 impl HasTypeProperties for DeriveInput {
@@ -39,13 +58,26 @@ impl HasTypeProperties for DeriveInput {
 
         let mut parse_err_ty_kw = None;
         let mut parse_err_fn_kw = None;
+        let mut parse_err_context_fn_kw = None;
         let mut serialize_all_kw = None;
         let mut ascii_case_insensitive_kw = None;
+        let mut case_insensitive_kw = None;
+        let mut trim_kw = None;
+        let mut separator_insensitive_kw = None;
         let mut use_phf_kw = None;
+        let mut use_decision_tree_kw = None;
         let mut crate_module_path_kw = None;
         let mut prefix_kw = None;
         let mut suffix_kw = None;
         let mut const_into_str = None;
+        let mut parse_fields_kw = None;
+        let mut optional_affixes_kw = None;
+        let mut parse_with_info_kw = None;
+        let mut parse_any_case_kw = None;
+        let mut parse_case_styles_kw = None;
+        let mut unique_prefix_kw = None;
+        let mut strict_locales_kw = None;
+        let mut to_string_template_kw = None;
 
         for meta in strum_meta {
             match meta {
@@ -65,14 +97,60 @@ impl HasTypeProperties for DeriveInput {
                     ascii_case_insensitive_kw = Some(kw);
                     output.ascii_case_insensitive = true;
                 }
+                EnumMeta::CaseInsensitive(kw) => {
+                    if let Some(fst_kw) = case_insensitive_kw {
+                        return Err(occurrence_error(fst_kw, kw, "case_insensitive"));
+                    }
+
+                    case_insensitive_kw = Some(kw);
+                    output.case_insensitive = true;
+                }
+                EnumMeta::Trim(kw) => {
+                    if let Some(fst_kw) = trim_kw {
+                        return Err(occurrence_error(fst_kw, kw, "trim"));
+                    }
+
+                    trim_kw = Some(kw);
+                    output.trim = true;
+                }
+                EnumMeta::SeparatorInsensitive(kw) => {
+                    if let Some(fst_kw) = separator_insensitive_kw {
+                        return Err(occurrence_error(fst_kw, kw, "separator_insensitive"));
+                    }
+
+                    separator_insensitive_kw = Some(kw);
+                    output.separator_insensitive = true;
+                }
                 EnumMeta::UsePhf(kw) => {
                     if let Some(fst_kw) = use_phf_kw {
                         return Err(occurrence_error(fst_kw, kw, "use_phf"));
                     }
 
+                    if use_decision_tree_kw.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "`use_phf` can't be combined with `use_decision_tree`",
+                        ));
+                    }
+
                     use_phf_kw = Some(kw);
                     output.use_phf = true;
                 }
+                EnumMeta::UseDecisionTree(kw) => {
+                    if let Some(fst_kw) = use_decision_tree_kw {
+                        return Err(occurrence_error(fst_kw, kw, "use_decision_tree"));
+                    }
+
+                    if use_phf_kw.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "`use_decision_tree` can't be combined with `use_phf`",
+                        ));
+                    }
+
+                    use_decision_tree_kw = Some(kw);
+                    output.use_decision_tree = true;
+                }
                 EnumMeta::Crate {
                     crate_module_path,
                     kw,
@@ -113,9 +191,31 @@ impl HasTypeProperties for DeriveInput {
                         return Err(occurrence_error(fst_kw, kw, "parse_err_fn"));
                     }
 
+                    if let Some(context_kw) = parse_err_context_fn_kw {
+                        return Err(syn::Error::new_spanned(
+                            context_kw,
+                            "`parse_err_context_fn` can't be combined with `parse_err_fn`",
+                        ));
+                    }
+
                     parse_err_fn_kw = Some(kw);
                     output.parse_err_fn = Some(path);
                 }
+                EnumMeta::ParseErrContextFn { path, kw } => {
+                    if let Some(fst_kw) = parse_err_context_fn_kw {
+                        return Err(occurrence_error(fst_kw, kw, "parse_err_context_fn"));
+                    }
+
+                    if parse_err_fn_kw.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "`parse_err_context_fn` can't be combined with `parse_err_fn`",
+                        ));
+                    }
+
+                    parse_err_context_fn_kw = Some(kw);
+                    output.parse_err_context_fn = Some(path);
+                }
                 EnumMeta::ConstIntoStr(kw) => {
                     if let Some(fst_kw) = const_into_str {
                         return Err(occurrence_error(fst_kw, kw, "const_into_str"));
@@ -124,6 +224,133 @@ impl HasTypeProperties for DeriveInput {
                     const_into_str = Some(kw);
                     output.const_into_str = true;
                 }
+                EnumMeta::ConstFromStr(kw) => {
+                    if let Some(fst_kw) = output.const_from_str {
+                        return Err(occurrence_error(fst_kw, kw, "const_from_str"));
+                    }
+
+                    output.const_from_str = Some(kw);
+                }
+                EnumMeta::ExtraTryFrom(kw) => {
+                    if let Some(fst_kw) = output.extra_try_from {
+                        return Err(occurrence_error(fst_kw, kw, "extra_try_from"));
+                    }
+
+                    output.extra_try_from = Some(kw);
+                }
+                EnumMeta::ParseList(kw) => {
+                    if let Some(fst_kw) = output.parse_list {
+                        return Err(occurrence_error(fst_kw, kw, "parse_list"));
+                    }
+
+                    output.parse_list = Some(kw);
+                }
+                EnumMeta::ParseWithInfo(kw) => {
+                    if let Some(fst_kw) = parse_with_info_kw {
+                        return Err(occurrence_error(fst_kw, kw, "parse_with_info"));
+                    }
+
+                    parse_with_info_kw = Some(kw);
+                    output.parse_with_info = true;
+                }
+                EnumMeta::ParseBorrowed(kw) => {
+                    if let Some(fst_kw) = output.parse_borrowed {
+                        return Err(occurrence_error(fst_kw, kw, "parse_borrowed"));
+                    }
+
+                    output.parse_borrowed = Some(kw);
+                }
+                EnumMeta::ParsePrefix(kw) => {
+                    if let Some(fst_kw) = output.parse_prefix {
+                        return Err(occurrence_error(fst_kw, kw, "parse_prefix"));
+                    }
+
+                    output.parse_prefix = Some(kw);
+                }
+                EnumMeta::Suggest(kw) => {
+                    if let Some(fst_kw) = output.suggest {
+                        return Err(occurrence_error(fst_kw, kw, "suggest"));
+                    }
+
+                    output.suggest = Some(kw);
+                }
+                EnumMeta::ParseFields(kw) => {
+                    if let Some(fst_kw) = parse_fields_kw {
+                        return Err(occurrence_error(fst_kw, kw, "parse_fields"));
+                    }
+
+                    parse_fields_kw = Some(kw);
+                    output.parse_fields = true;
+                }
+                EnumMeta::OptionalAffixes(kw) => {
+                    if let Some(fst_kw) = optional_affixes_kw {
+                        return Err(occurrence_error(fst_kw, kw, "optional_affixes"));
+                    }
+
+                    optional_affixes_kw = Some(kw);
+                    output.optional_affixes = true;
+                }
+                EnumMeta::ParseAnyCase(kw) => {
+                    if let Some(fst_kw) = parse_any_case_kw {
+                        return Err(occurrence_error(fst_kw, kw, "parse_any_case"));
+                    }
+
+                    if parse_case_styles_kw.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "`parse_any_case` can't be combined with `parse_case_styles`",
+                        ));
+                    }
+
+                    parse_any_case_kw = Some(kw);
+                    output.parse_case_styles = CaseStyle::ALL.to_vec();
+                }
+                EnumMeta::ParseCaseStyles { case_styles, kw } => {
+                    if let Some(fst_kw) = parse_case_styles_kw {
+                        return Err(occurrence_error(fst_kw, kw, "parse_case_styles"));
+                    }
+
+                    if parse_any_case_kw.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "`parse_case_styles` can't be combined with `parse_any_case`",
+                        ));
+                    }
+
+                    parse_case_styles_kw = Some(kw);
+                    output.parse_case_styles = case_styles;
+                }
+                EnumMeta::UniquePrefix(kw) => {
+                    if let Some(fst_kw) = unique_prefix_kw {
+                        return Err(occurrence_error(fst_kw, kw, "unique_prefix"));
+                    }
+
+                    unique_prefix_kw = Some(kw);
+                    output.unique_prefix = true;
+                }
+                EnumMeta::ParseRepr(kw) => {
+                    if let Some(fst_kw) = output.parse_repr {
+                        return Err(occurrence_error(fst_kw, kw, "parse_repr"));
+                    }
+
+                    output.parse_repr = Some(kw);
+                }
+                EnumMeta::StrictLocales(kw) => {
+                    if let Some(fst_kw) = strict_locales_kw {
+                        return Err(occurrence_error(fst_kw, kw, "strict_locales"));
+                    }
+
+                    strict_locales_kw = Some(kw);
+                    output.strict_locales = true;
+                }
+                EnumMeta::ToStringTemplate { kw, template } => {
+                    if let Some(fst_kw) = to_string_template_kw {
+                        return Err(occurrence_error(fst_kw, kw, "to_string_template"));
+                    }
+
+                    to_string_template_kw = Some(kw);
+                    output.to_string_template = Some(template);
+                }
             }
         }
 
@@ -4,6 +4,7 @@ use syn::{Ident, Lit, LitStr, Variant};
 use super::case_style::{CaseStyle, CaseStyleHelpers};
 use super::metadata::{kw, VariantExt, VariantMeta};
 use super::occurrence_error;
+use super::template::{parse_template, Segment};
 
 pub trait HasStrumVariantProperties {
     fn get_variant_properties(&self) -> syn::Result<StrumVariantProperties>;
@@ -14,14 +15,24 @@ pub struct StrumVariantProperties {
     pub transparent: Option<kw::transparent>,
     pub disabled: Option<kw::disabled>,
     pub default: Option<kw::default>,
+    pub default_from_str: Option<kw::default_from_str>,
     pub default_with: Option<LitStr>,
     pub ascii_case_insensitive: Option<bool>,
+    pub case_insensitive: Option<bool>,
+    pub trim: Option<bool>,
+    pub separator_insensitive: Option<bool>,
     pub message: Option<LitStr>,
     pub detailed_message: Option<LitStr>,
     pub documentation: Vec<LitStr>,
     pub props: Vec<(LitStr, Lit)>,
+    pub parse_fields: Option<kw::parse_fields>,
     serialize: Vec<LitStr>,
+    pub deprecated_aliases: Vec<LitStr>,
+    pub patterns: Vec<LitStr>,
     pub to_string: Option<LitStr>,
+    pub to_string_alt: Option<LitStr>,
+    pub localized_to_string: Vec<(LitStr, LitStr)>,
+    pub localized_messages: Vec<(LitStr, LitStr)>,
     ident: Option<Ident>,
 }
 
@@ -37,13 +48,11 @@ impl StrumVariantProperties {
         prefix: Option<&LitStr>,
         suffix: Option<&LitStr>,
     ) -> LitStr {
-        let mut output = self.to_string.as_ref().cloned().unwrap_or_else(|| {
-            self.serialize
-                .iter()
-                .max_by_key(|s| s.value().len())
-                .cloned()
-                .unwrap_or_else(|| self.ident_as_str(case_style))
-        });
+        let mut output = self
+            .to_string
+            .as_ref()
+            .cloned()
+            .unwrap_or_else(|| self.get_serialized_name(case_style));
 
         if let Some(prefix) = prefix {
             output = LitStr::new(&(prefix.value() + &output.value()), output.span());
@@ -56,6 +65,45 @@ impl StrumVariantProperties {
         output
     }
 
+    /// Fails if `to_string` uses `{__variant}`, `{__message}` or `{__discriminant}`. Only `Display`
+    /// expands them, so `derive` would return the template as is.
+    pub fn reject_reserved_placeholders(&self, derive: &str) -> syn::Result<()> {
+        let to_string = match &self.to_string {
+            Some(to_string) => to_string,
+            None => return Ok(()),
+        };
+
+        // A string that isn't a valid template has no placeholders either.
+        for segment in parse_template(to_string).unwrap_or_default() {
+            match segment {
+                Segment::Placeholder { name, .. }
+                    if matches!(name.as_str(), "__variant" | "__message" | "__discriminant") =>
+                {
+                    return Err(syn::Error::new_spanned(
+                        to_string,
+                        format!(
+                            "`{{{}}}` is only expanded by `Display`, so it can't be used with `{}`",
+                            name, derive
+                        ),
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The longest `serialize` of the variant, or its name in `case_style` without one. Unlike
+    /// `get_preferred_name`, this ignores `to_string` and the enum's affixes.
+    pub fn get_serialized_name(&self, case_style: Option<CaseStyle>) -> LitStr {
+        self.serialize
+            .iter()
+            .max_by_key(|s| s.value().len())
+            .cloned()
+            .unwrap_or_else(|| self.ident_as_str(case_style))
+    }
+
     pub fn get_serializations(&self, case_style: Option<CaseStyle>) -> Vec<LitStr> {
         let mut attrs = self.serialize.clone();
         if let Some(to_string) = &self.to_string {
@@ -68,6 +116,24 @@ impl StrumVariantProperties {
 
         attrs
     }
+
+    /// The variant's name converted to each of `case_styles`. Variants with an explicit
+    /// `serialize` or `to_string` keep parsing only those.
+    pub fn get_case_style_serializations(&self, case_styles: &[CaseStyle]) -> Vec<LitStr> {
+        if !self.serialize.is_empty() || self.to_string.is_some() {
+            return Vec::new();
+        }
+
+        let mut attrs: Vec<LitStr> = Vec::new();
+        for case_style in case_styles {
+            let serialization = self.ident_as_str(Some(*case_style));
+            if !attrs.iter().any(|s| s.value() == serialization.value()) {
+                attrs.push(serialization);
+            }
+        }
+
+        attrs
+    }
 }
 
 impl HasStrumVariantProperties for Variant {
@@ -84,7 +150,11 @@ impl HasStrumVariantProperties for Variant {
         let mut default_kw = None;
         let mut default_with_kw = None;
         let mut to_string_kw = None;
+        let mut to_string_alt_kw = None;
         let mut ascii_case_insensitive_kw = None;
+        let mut case_insensitive_kw = None;
+        let mut trim_kw = None;
+        let mut separator_insensitive_kw = None;
         for meta in self.get_metadata()? {
             match meta {
                 VariantMeta::Message { value, kw } => {
@@ -109,6 +179,19 @@ impl HasStrumVariantProperties for Variant {
                 VariantMeta::Serialize { value, .. } => {
                     output.serialize.push(value);
                 }
+                VariantMeta::DeprecatedAlias { value, .. } => {
+                    output.deprecated_aliases.push(value);
+                }
+                VariantMeta::Pattern { value, .. } => {
+                    if !value.value().contains(['?', '*']) {
+                        return Err(syn::Error::new_spanned(
+                            value,
+                            "`pattern` needs a `?` or `*`, use `serialize` for a single string",
+                        ));
+                    }
+
+                    output.patterns.push(value);
+                }
                 VariantMeta::ToString { value, kw } => {
                     if let Some(fst_kw) = to_string_kw {
                         return Err(occurrence_error(fst_kw, kw, "to_string"));
@@ -117,6 +200,30 @@ impl HasStrumVariantProperties for Variant {
                     to_string_kw = Some(kw);
                     output.to_string = Some(value);
                 }
+                VariantMeta::LocalizedToString { locale, value, .. } => {
+                    if let Some((fst, _)) = find_locale(&output.localized_to_string, &locale) {
+                        let attr = format!("to_string(locale = {:?})", locale.value());
+                        return Err(occurrence_error(fst, &locale, &attr));
+                    }
+
+                    output.localized_to_string.push((locale, value));
+                }
+                VariantMeta::LocalizedMessage { locale, value, .. } => {
+                    if let Some((fst, _)) = find_locale(&output.localized_messages, &locale) {
+                        let attr = format!("message(locale = {:?})", locale.value());
+                        return Err(occurrence_error(fst, &locale, &attr));
+                    }
+
+                    output.localized_messages.push((locale, value));
+                }
+                VariantMeta::ToStringAlt { value, kw } => {
+                    if let Some(fst_kw) = to_string_alt_kw {
+                        return Err(occurrence_error(fst_kw, kw, "to_string_alt"));
+                    }
+
+                    to_string_alt_kw = Some(kw);
+                    output.to_string_alt = Some(value);
+                }
                 VariantMeta::Transparent(kw) => {
                     if let Some(fst_kw) = transparent_kw {
                         return Err(occurrence_error(fst_kw, kw, "transparent"));
@@ -138,9 +245,31 @@ impl HasStrumVariantProperties for Variant {
                         return Err(occurrence_error(fst_kw, kw, "default"));
                     }
 
+                    if output.default_from_str.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "`default_from_str` already implies `default`",
+                        ));
+                    }
+
                     default_kw = Some(kw);
                     output.default = Some(kw);
                 }
+                VariantMeta::DefaultFromStr(kw) => {
+                    if let Some(fst_kw) = output.default_from_str {
+                        return Err(occurrence_error(fst_kw, kw, "default_from_str"));
+                    }
+
+                    if default_kw.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            kw,
+                            "`default_from_str` already implies `default`",
+                        ));
+                    }
+
+                    output.default_from_str = Some(kw);
+                    output.default = Some(kw::default(kw.span));
+                }
                 VariantMeta::DefaultWith { kw, value } => {
                     if let Some(fst_kw) = default_with_kw {
                         return Err(occurrence_error(fst_kw, kw, "default_with"));
@@ -157,12 +286,53 @@ impl HasStrumVariantProperties for Variant {
                     ascii_case_insensitive_kw = Some(kw);
                     output.ascii_case_insensitive = Some(value);
                 }
+                VariantMeta::CaseInsensitive { kw, value } => {
+                    if let Some(fst_kw) = case_insensitive_kw {
+                        return Err(occurrence_error(fst_kw, kw, "case_insensitive"));
+                    }
+
+                    case_insensitive_kw = Some(kw);
+                    output.case_insensitive = Some(value);
+                }
+                VariantMeta::Trim { kw, value } => {
+                    if let Some(fst_kw) = trim_kw {
+                        return Err(occurrence_error(fst_kw, kw, "trim"));
+                    }
+
+                    trim_kw = Some(kw);
+                    output.trim = Some(value);
+                }
+                VariantMeta::SeparatorInsensitive { kw, value } => {
+                    if let Some(fst_kw) = separator_insensitive_kw {
+                        return Err(occurrence_error(fst_kw, kw, "separator_insensitive"));
+                    }
+
+                    separator_insensitive_kw = Some(kw);
+                    output.separator_insensitive = Some(value);
+                }
                 VariantMeta::Props { props, .. } => {
                     output.props.extend(props);
                 }
+                VariantMeta::ParseFields(kw) => {
+                    if let Some(fst_kw) = output.parse_fields {
+                        return Err(occurrence_error(fst_kw, kw, "parse_fields"));
+                    }
+
+                    output.parse_fields = Some(kw);
+                }
             }
         }
 
         Ok(output)
     }
 }
+
+/// The translation of `translations` for `locale`, if there already is one.
+fn find_locale<'a>(
+    translations: &'a [(LitStr, LitStr)],
+    locale: &LitStr,
+) -> Option<&'a (LitStr, LitStr)> {
+    translations
+        .iter()
+        .find(|(l, _)| l.value() == locale.value())
+}
@@ -41,9 +41,24 @@ fn debug_print_generated(ast: &DeriveInput, toks: &TokenStream) {
 /// Multiple deserializations can be added to the same variant. If the variant contains additional data,
 /// they will be set to their default values upon deserialization.
 ///
+/// With `#[strum(extra_try_from)]`, `TryFrom<&[u8]>` is derived too and compares bytes directly, so
+/// input from a socket or a file header doesn't need converting first. With strum's `std` feature,
+/// `TryFrom<&OsStr>`, `TryFrom<String>` and `TryFrom<Cow<str>>` are also derived. They all accept
+/// exactly what `FromStr` accepts; input that isn't UTF-8 is rejected, even by a `default` variant.
+///
+/// With `#[strum(parse_list)]`, the enum also gets `parse_list(s, sep)`, which returns a
+/// `strum::ParseList` iterator parsing every `sep` separated item of `s`, and
+/// `collect_list(s, sep)`, which collects those items into any `FromIterator` or returns the first
+/// error. Both panic if `sep` is empty. `ParseList::reject_duplicates` turns repeated variants
+/// into `ParseError::Duplicate` errors. Two items of the `default` variant are only repeated if
+/// their values are equal, so its field has to implement `PartialEq`.
+///
 /// The `default` attribute can be applied to a tuple variant with a single data parameter. When a match isn't
 /// found, the given variant will be returned and the input string will be captured in the parameter.
 ///
+/// With the `parse_fields` attribute, a `to_string` containing placeholders is used as a pattern instead, and
+/// each captured piece is parsed into the matching field, e.g. `"Rgb({r}, {g}, {b})"` parses `"Rgb(1, 2, 3)"`.
+///
 /// Note that the implementation of `FromStr` by default only matches on the name of the
 /// variant. There is an option to match on different case conversions through the
 /// `#[strum(serialize_all = "snake_case")]` type attribute.
@@ -57,11 +72,49 @@ fn debug_print_generated(ast: &DeriveInput, toks: &TokenStream) {
 /// rather than just assume it will be faster. With SIMD + pipelining, linear string search (aka memcmp)
 /// can be very fast for enums with a surprisingly large number of enum variants.
 ///
-/// The default error type is `strum::ParseError`. This can be overriden by applying both the
+/// `use_decision_tree` is a dependency-free alternative that also works in `no_std`. The input is
+/// dispatched on its length and then on individual bytes, so only one serialization is compared in
+/// full, even for `ascii_case_insensitive` variants.
+///
+/// The default error type is `strum::ParseError`. When no variant matches, it holds a
+/// `strum::VariantNotFound` recording the rejected input and every string the enum accepts, and
+/// its `Display` lists those strings. This can be overriden by applying both the
 /// `parse_err_ty` and `parse_err_fn` attributes at the type level.  `parse_error_fn` should be a
 /// function that accepts an `&str` and returns the type `parse_error_ty`. See
 /// [this test case](https://github.com/Peternator7/strum/blob/9db3c4dc9b6f585aeb9f5f15f9cc18b6cf4fd780/strum_tests/tests/from_str.rs#L233)
-/// for an example.
+/// for an example. Use `parse_err_context_fn` instead of `parse_err_fn` for a function that accepts
+/// a `strum::ParseErrorContext`, which also holds the name of the enum and the accepted strings.
+///
+/// Variants can keep parsing from old names with `#[strum(deprecated_alias = "...")]`. With
+/// `#[strum(parse_with_info)]`, the generated `parse_with_info` returns a `strum::ParseInfo`
+/// alongside the variant, telling whether the input was one of those aliases.
+///
+/// Families of strings can be mapped to a variant with `#[strum(pattern = "E1??")]`, where `?` matches
+/// any one character and `*` any run of characters. Patterns are tried in declaration order, after
+/// every exact serialization.
+///
+/// With `#[strum(parse_repr)]`, a number is also accepted if it's the discriminant of a variant, as
+/// in `FromRepr`.
+///
+/// The default variant of an enum with a single lifetime parameter may borrow from the input, e.g.
+/// `Other(&'a str)`. `TryFrom<&'a str>` then replaces `FromStr` and `TryFrom<&str>`. With
+/// `#[strum(parse_borrowed)]`, enums with a single lifetime parameter also get
+/// `parse_borrowed(s: &'a str)`, whether or not their default variant borrows.
+///
+/// For tokenizers, `#[strum(parse_prefix)]` derives `parse_prefix(s)`, which returns the variant
+/// whose serialization is the longest prefix of `s`, along with the rest of `s`. Only exact
+/// serializations are considered, honouring `ascii_case_insensitive`: the default variant,
+/// `pattern`s, `parse_fields` templates and `unique_prefix` abbreviations are left out. It can't be
+/// combined with `trim`, `case_insensitive` or `separator_insensitive`, which have no clear meaning
+/// for the start of a longer string.
+///
+/// Two variants that parse from the same string are rejected at compile time, as that string
+/// could only ever produce the first of them. With `ascii_case_insensitive`, strings that only
+/// differ by ASCII case count as the same.
+///
+/// If the enum has a `prefix` or `suffix`, the input must start and end with them. With
+/// `#[strum(optional_affixes)]` they may also be left out. The default variant and the errors
+/// are given the whole input.
 ///
 /// # Example how to use `EnumString`
 /// ```
@@ -84,9 +137,13 @@ fn debug_print_generated(ast: &DeriveInput, toks: &TokenStream) {
 ///     #[strum(disabled)]
 ///     Yellow,
 ///
-///     // We can make the comparison case insensitive (however Unicode is not supported at the moment)
+///     // We can make the comparison case insensitive
 ///     #[strum(ascii_case_insensitive)]
 ///     Black,
+///
+///     // Or ignore surrounding whitespace, Unicode case and separators like `-` and `_`
+///     #[strum(trim, case_insensitive, separator_insensitive)]
+///     DarkGray,
 /// }
 ///
 /// /*
@@ -95,13 +152,19 @@ fn debug_print_generated(ast: &DeriveInput, toks: &TokenStream) {
 ///     type Err = ::strum::ParseError;
 ///
 ///     fn from_str(s: &str) -> ::core::result::Result<Color, Self::Err> {
+///         const EXPECTED: &[&str] = &["Red", "Green", "blue", "b", "Black", "DarkGray"];
 ///         match s {
 ///             "Red" => ::core::result::Result::Ok(Color::Red),
 ///             "Green" => ::core::result::Result::Ok(Color::Green { range:Default::default() }),
 ///             "blue" => ::core::result::Result::Ok(Color::Blue(Default::default())),
 ///             "b" => ::core::result::Result::Ok(Color::Blue(Default::default())),
 ///             s if s.eq_ignore_ascii_case("Black") => ::core::result::Result::Ok(Color::Black),
-///             _ => ::core::result::Result::Err(::strum::ParseError::VariantNotFound),
+///             // The input is trimmed, case folded and stripped of separators once, then
+///             // compared to "darkgray".
+///             s if normalized(s) == "darkgray" => ::core::result::Result::Ok(Color::DarkGray),
+///             _ => ::core::result::Result::Err(::strum::ParseError::VariantNotFound(
+///                 ::strum::VariantNotFound::new(s, EXPECTED),
+///             )),
 ///         }
 ///     }
 /// }
@@ -120,6 +183,8 @@ fn debug_print_generated(ast: &DeriveInput, toks: &TokenStream) {
 /// println!("{:?}", Color::Yellow);
 /// let color_variant = Color::from_str("bLACk").unwrap();
 /// assert_eq!(Color::Black, color_variant);
+/// let color_variant = Color::from_str(" dark-gray ").unwrap();
+/// assert_eq!(Color::DarkGray, color_variant);
 /// ```
 #[proc_macro_derive(EnumString, attributes(strum))]
 pub fn from_string(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -399,7 +464,9 @@ pub fn to_string(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// 5. If the enum has a `strum(suffix = "_another_value")`, every variant will have that suffix appended
 ///    to the serialization.
 /// 6. Enums with fields support string interpolation.
-///    Note this means the variant will not "round trip" if you then deserialize the string.
+///    Note this means the variant will not "round trip" if you then deserialize the string, unless
+///    `EnumString` is derived with the `parse_fields` attribute. The output is written straight into
+///    the `Formatter` without allocating, and padded or truncated like a `str`.
 ///
 ///    ```rust
 ///    #[derive(strum_macros::Display)]
@@ -409,6 +476,49 @@ pub fn to_string(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///        #[strum(to_string = "hue is {1}, saturation is {0}")]
 ///        Blue(usize, usize),
 ///    }
+///
+///    assert_eq!("[saturation is 5   ]", format!("[{:18}]", Color::Red { sat: 5 }));
+///    ```
+/// 7. With `#[strum(to_string_alt = "...")]`, a variant is written differently when formatted with
+///    `{:#}`. It supports the same interpolation as `to_string`.
+///
+///    ```rust
+///    #[derive(strum_macros::Display)]
+///    pub enum Unit {
+///        #[strum(to_string = "km", to_string_alt = "kilometers")]
+///        Kilometer,
+///        #[strum(to_string = "{0}e", to_string_alt = "{0} electrons")]
+///        Charge(u32),
+///    }
+///
+///    assert_eq!("km", format!("{}", Unit::Kilometer));
+///    assert_eq!("kilometers", format!("{:#}", Unit::Kilometer));
+///    assert_eq!("3 electrons", format!("{:#}", Unit::Charge(3)));
+///    ```
+/// 8. Placeholders can reach into fields with a path like `{0.code}` or `{inner.name}`, and take a
+///    format spec like `{0:>4}`. `{__variant}` is replaced by the variant's serialized name,
+///    `{__message}` by its `message` and `{__discriminant}` by its discriminant. With an enum-level
+///    `#[strum(to_string_template = "...")]`, every variant without its own `to_string` is written
+///    with that template instead of its name.
+///
+///    ```rust
+///    pub struct Span {
+///        line: u32,
+///    }
+///
+///    #[derive(strum_macros::Display)]
+///    #[strum(serialize_all = "snake_case", to_string_template = "{__variant}: {0}")]
+///    pub enum Error {
+///        UnknownField(String),
+///        #[strum(message = "Unexpected end of input", to_string = "{__message} at line {0.line}")]
+///        Eof(Span),
+///    }
+///
+///    assert_eq!("unknown_field: id", Error::UnknownField("id".into()).to_string());
+///    assert_eq!(
+///        "Unexpected end of input at line 3",
+///        Error::Eof(Span { line: 3 }).to_string()
+///    );
 ///    ```
 ///
 /// ```
@@ -459,6 +569,44 @@ pub fn display(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     toks.into()
 }
 
+/// Implements `std::fmt::Debug` using the names strum gives the variants.
+///
+/// Unit variants are written as what `Display` would write for them, which honours `serialize_all`,
+/// `serialize`, `to_string`, `prefix` and `suffix`. Variants with data are written with
+/// `Formatter::debug_tuple` or `Formatter::debug_struct` under the same name, so `{:#?}` still
+/// pretty prints them. If their `to_string` interpolates fields, the name it would have without
+/// `to_string` is used instead, as the fields are listed anyway. `transparent` variants forward to
+/// the `Debug` of their field, and `disabled` variants keep their Rust name.
+///
+/// ```
+/// #[derive(strum_macros::StrumDebug)]
+/// #[strum(serialize_all = "snake_case")]
+/// enum Request {
+///     HealthCheck,
+///     #[strum(to_string = "get {path}")]
+///     Get { path: &'static str, retries: u8 },
+///     SetFlag(&'static str, bool),
+///     #[strum(transparent)]
+///     Raw(String),
+/// }
+///
+/// assert_eq!("health_check", format!("{:?}", Request::HealthCheck));
+/// assert_eq!(
+///     r#"get { path: "/", retries: 2 }"#,
+///     format!("{:?}", Request::Get { path: "/", retries: 2 })
+/// );
+/// assert_eq!(r#"set_flag("beta", true)"#, format!("{:?}", Request::SetFlag("beta", true)));
+/// assert_eq!(r#""GET /""#, format!("{:?}", Request::Raw("GET /".into())));
+/// ```
+#[proc_macro_derive(StrumDebug, attributes(strum))]
+pub fn strum_debug(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+
+    let toks = macros::debug::debug_inner(&ast).unwrap_or_else(|err| err.to_compile_error());
+    debug_print_generated(&ast, &toks);
+    toks.into()
+}
+
 /// Creates a new type that iterates over the variants of an enum.
 ///
 /// Iterate over the variants of an Enum. Any additional data on your variants will be set to `Default::default()`.
@@ -783,6 +931,44 @@ pub fn enum_messages(input: proc_macro::TokenStream) -> proc_macro::TokenStream
     toks.into()
 }
 
+/// Translates the labels and messages of variants. Implements `strum::Localized`.
+///
+/// Translations are given with `#[strum(to_string(locale = "...") = "...")]` and
+/// `#[strum(message(locale = "...") = "...")]`, and `Localized::LOCALES` lists every locale used.
+/// `to_localized` falls back to the text `Display` writes when a variant has no translation for a
+/// locale, and `get_localized_message` to the variant's `message`. Locales are compared exactly.
+///
+/// Mark the enum `#[strum(strict_locales)]` to turn missing translations into compile errors:
+/// every variant then needs a `to_string` for each locale in `LOCALES`, and every variant with
+/// a message needs a `message` for each of them too.
+///
+/// ```
+/// use strum::Localized;
+///
+/// #[derive(strum_macros::Localized, strum_macros::Display)]
+/// #[strum(strict_locales)]
+/// enum Light {
+///     #[strum(to_string(locale = "fr") = "Allumé", to_string(locale = "es") = "Encendido")]
+///     On,
+///     #[strum(to_string = "off", to_string(locale = "fr") = "Éteint")]
+///     #[strum(to_string(locale = "es") = "Apagado")]
+///     Off,
+/// }
+///
+/// assert_eq!(Some("Éteint"), Light::Off.to_localized("fr"));
+/// assert_eq!(Some("off"), Light::Off.to_localized("it"));
+/// assert_eq!(["fr", "es"], Light::LOCALES);
+/// ```
+#[proc_macro_derive(Localized, attributes(strum))]
+pub fn localized(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+
+    let toks =
+        macros::localized::localized_inner(&ast).unwrap_or_else(|err| err.to_compile_error());
+    debug_print_generated(&ast, &toks);
+    toks.into()
+}
+
 /// Add custom properties to enum variants.
 ///
 /// Enables the encoding of arbitrary constants into enum variants. This method
@@ -824,7 +1010,6 @@ pub fn enum_messages(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 /// );
 /// assert_eq!("My color is Red. It\'s RGB is 255,0,0", &display);
 /// ```
-
 #[proc_macro_derive(EnumProperty, attributes(strum))]
 pub fn enum_properties(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse_macro_input!(input as DeriveInput);
@@ -35,6 +35,8 @@ pub fn enum_message_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
 
         // You can't disable getting the serializations.
         {
+            variant_properties.reject_reserved_placeholders("EnumMessage")?;
+
             let serialization_variants =
                 variant_properties.get_serializations(type_properties.case_style);
 
@@ -64,9 +64,7 @@ pub fn enum_try_as_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
                         }
                     })
                 },
-                _ => {
-                    return None;
-                }
+                _ => None,
             }
 
         })
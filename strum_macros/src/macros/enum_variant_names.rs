@@ -22,6 +22,7 @@ pub fn enum_variant_names_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         .iter()
         .map(|v| {
             let props = v.get_variant_properties()?;
+            props.reject_reserved_placeholders("VariantNames")?;
             Ok(props.get_preferred_name(
                 type_properties.case_style,
                 type_properties.prefix.as_ref(),
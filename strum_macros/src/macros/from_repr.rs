@@ -1,4 +1,4 @@
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote};
 use syn::{Data, DeriveInput, Fields, Type};
 
@@ -10,6 +10,64 @@ pub fn from_repr_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let (impl_generics, ty_generics, where_clause) = gen.split_for_impl();
     let vis = &ast.vis;
 
+    if gen.lifetimes().count() > 0 {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "This macro doesn't support enums with lifetimes. \
+             The resulting enums would be unbounded.",
+        ));
+    }
+
+    let DiscriminantTable {
+        discriminant_type,
+        constant_defs,
+        arms,
+        has_additional_data,
+    } = discriminant_table(ast)?;
+
+    let const_if_possible = if has_additional_data {
+        quote! {}
+    } else {
+        quote! { const }
+    };
+
+    Ok(quote! {
+        #[allow(clippy::use_self)]
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[doc = "Try to create [Self] from the raw representation"]
+            #[inline]
+            #vis #const_if_possible fn from_repr(discriminant: #discriminant_type) -> Option<#name #ty_generics> {
+                #(#constant_defs)*
+                match discriminant {
+                    #(#arms),*
+                }
+            }
+        }
+    })
+}
+
+/// How `from_repr` maps a `discriminant` back to its variant. Also used by `EnumString` for
+/// `#[strum(parse_repr)]`.
+pub struct DiscriminantTable {
+    /// The `#[repr]` of the enum if it's an integer, `usize` otherwise.
+    pub discriminant_type: Type,
+    /// A constant holding the discriminant of each variant.
+    pub constant_defs: Vec<TokenStream>,
+    /// Match arms evaluating to `Some(variant)`, or `None` if no variant has the discriminant.
+    pub arms: Vec<TokenStream>,
+    pub has_additional_data: bool,
+}
+
+/// The name of the constant in `DiscriminantTable::constant_defs` holding the discriminant of
+/// `variant`.
+pub fn discriminant_const(variant: &Ident) -> Ident {
+    format_ident!("{}_DISCRIMINANT", variant)
+}
+
+pub fn discriminant_table(ast: &DeriveInput) -> syn::Result<DiscriminantTable> {
+    let name = &ast.ident;
+
     let mut discriminant_type: Type = syn::parse("usize".parse().unwrap()).unwrap();
     if let Some(type_path) = ast
         .get_type_properties()
@@ -31,14 +89,6 @@ pub fn from_repr_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         }
     }
 
-    if gen.lifetimes().count() > 0 {
-        return Err(syn::Error::new(
-            Span::call_site(),
-            "This macro doesn't support enums with lifetimes. \
-             The resulting enums would be unbounded.",
-        ));
-    }
-
     let variants = match &ast.data {
         Data::Enum(v) => &v.variants,
         _ => return Err(non_enum_error()),
@@ -49,11 +99,27 @@ pub fn from_repr_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let mut has_additional_data = false;
     let mut prev_const_var_ident = None;
     for variant in variants {
+        let ident = &variant.ident;
+        let const_var_ident = discriminant_const(ident);
+        let const_val_expr = match &variant.discriminant {
+            Some((_, expr)) => quote! { #expr },
+            None => match &prev_const_var_ident {
+                Some(prev) => quote! { #prev + 1 },
+                None => quote! { 0 },
+            },
+        };
+
+        // Disabled variants still take up a discriminant, so the ones after them keep theirs.
+        constant_defs.push(quote! {
+            #[allow(non_upper_case_globals)]
+            const #const_var_ident: #discriminant_type = #const_val_expr;
+        });
+        prev_const_var_ident = Some(const_var_ident.clone());
+
         if variant.get_variant_properties()?.disabled.is_some() {
             continue;
         }
 
-        let ident = &variant.ident;
         let params = match &variant.fields {
             Fields::Unit => quote! {},
             Fields::Unnamed(fields) => {
@@ -72,46 +138,15 @@ pub fn from_repr_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
             }
         };
 
-        let const_var_str = format!("{}_DISCRIMINANT", variant.ident);
-        let const_var_ident = format_ident!("{}", const_var_str);
-
-        let const_val_expr = match &variant.discriminant {
-            Some((_, expr)) => quote! { #expr },
-            None => match &prev_const_var_ident {
-                Some(prev) => quote! { #prev + 1 },
-                None => quote! { 0 },
-            },
-        };
-
-        constant_defs.push(quote! {
-            #[allow(non_upper_case_globals)]
-            const #const_var_ident: #discriminant_type = #const_val_expr;
-        });
         arms.push(quote! {v if v == #const_var_ident => ::core::option::Option::Some(#name::#ident #params)});
-
-        prev_const_var_ident = Some(const_var_ident);
     }
 
     arms.push(quote! { _ => ::core::option::Option::None });
 
-    let const_if_possible = if has_additional_data {
-        quote! {}
-    } else {
-        quote! { const }
-    };
-
-    Ok(quote! {
-        #[allow(clippy::use_self)]
-        #[automatically_derived]
-        impl #impl_generics #name #ty_generics #where_clause {
-            #[doc = "Try to create [Self] from the raw representation"]
-            #[inline]
-            #vis #const_if_possible fn from_repr(discriminant: #discriminant_type) -> Option<#name #ty_generics> {
-                #(#constant_defs)*
-                match discriminant {
-                    #(#arms),*
-                }
-            }
-        }
+    Ok(DiscriminantTable {
+        discriminant_type,
+        constant_defs,
+        arms,
+        has_additional_data,
     })
 }
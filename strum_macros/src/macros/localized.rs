@@ -0,0 +1,177 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, LitStr};
+
+use crate::helpers::template::{parse_template, Segment};
+use crate::helpers::{non_enum_error, HasStrumVariantProperties, HasTypeProperties};
+
+pub fn localized_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let variants = match &ast.data {
+        Data::Enum(v) => &v.variants,
+        _ => return Err(non_enum_error()),
+    };
+
+    let type_properties = ast.get_type_properties()?;
+    let strum_module_path = type_properties.crate_module_path();
+
+    let mut locales: Vec<LitStr> = Vec::new();
+    let mut enabled = Vec::new();
+    for variant in variants {
+        let variant_properties = variant.get_variant_properties()?;
+        if variant_properties.disabled.is_some() {
+            continue;
+        }
+
+        let translations = variant_properties
+            .localized_to_string
+            .iter()
+            .chain(&variant_properties.localized_messages);
+        for (locale, _) in translations {
+            if !locales.iter().any(|l| l.value() == locale.value()) {
+                locales.push(locale.clone());
+            }
+        }
+
+        enabled.push((variant, variant_properties));
+    }
+
+    let mut label_arms = Vec::new();
+    let mut message_arms = Vec::new();
+    let mut missing: Option<syn::Error> = None;
+    for (variant, variant_properties) in &enabled {
+        let ident = &variant.ident;
+        let params = match variant.fields {
+            Fields::Unit => quote! {},
+            Fields::Unnamed(..) => quote! { (..) },
+            Fields::Named(..) => quote! { {..} },
+        };
+
+        let has_message = variant_properties.message.is_some()
+            || !variant_properties.localized_messages.is_empty();
+        if type_properties.strict_locales {
+            for locale in &locales {
+                let translated = |translations: &[(LitStr, LitStr)]| {
+                    translations
+                        .iter()
+                        .any(|(l, _)| l.value() == locale.value())
+                };
+
+                let mut report = |attr: &str| {
+                    let e = syn::Error::new_spanned(
+                        ident,
+                        format!(
+                            "`{}` has no `{}` for locale {:?}",
+                            ident,
+                            attr,
+                            locale.value()
+                        ),
+                    );
+                    match &mut missing {
+                        Some(missing) => missing.combine(e),
+                        None => missing = Some(e),
+                    }
+                };
+
+                if !translated(&variant_properties.localized_to_string) {
+                    report("to_string");
+                }
+
+                if has_message && !translated(&variant_properties.localized_messages) {
+                    report("message");
+                }
+            }
+        }
+
+        // Without a translation, fall back to what `Display` writes if that's a fixed string.
+        let fallback = if variant_properties.transparent.is_some()
+            || (variant_properties.default.is_some() && variant_properties.to_string.is_none())
+        {
+            None
+        } else {
+            let mut preferred = variant_properties.clone();
+            if preferred.to_string.is_none() {
+                preferred.to_string = type_properties.to_string_template.clone();
+            }
+
+            let output = preferred.get_preferred_name(
+                type_properties.case_style,
+                type_properties.prefix.as_ref(),
+                type_properties.suffix.as_ref(),
+            );
+            let segments = parse_template(&output)?;
+            if segments
+                .iter()
+                .any(|segment| matches!(segment, Segment::Placeholder { .. }))
+            {
+                None
+            } else {
+                Some(output)
+            }
+        };
+        let fallback = match fallback {
+            Some(fallback) => quote! { ::core::option::Option::Some(#fallback) },
+            None => quote! { ::core::option::Option::None },
+        };
+
+        let labels = variant_properties
+            .localized_to_string
+            .iter()
+            .map(|(locale, value)| quote! { #locale => ::core::option::Option::Some(#value), });
+        label_arms.push(quote! {
+            &#name::#ident #params => match locale {
+                #(#labels)*
+                _ => #fallback,
+            }
+        });
+
+        if has_message {
+            let message = match &variant_properties.message {
+                Some(message) => quote! { ::core::option::Option::Some(#message) },
+                None => quote! { ::core::option::Option::None },
+            };
+            let messages = variant_properties
+                .localized_messages
+                .iter()
+                .map(|(locale, value)| quote! { #locale => ::core::option::Option::Some(#value), });
+            message_arms.push(quote! {
+                &#name::#ident #params => match locale {
+                    #(#messages)*
+                    _ => #message,
+                }
+            });
+        }
+    }
+
+    if let Some(missing) = missing {
+        return Err(missing);
+    }
+
+    if label_arms.len() < variants.len() {
+        label_arms.push(quote! { _ => ::core::option::Option::None });
+    }
+
+    if message_arms.len() < variants.len() {
+        message_arms.push(quote! { _ => ::core::option::Option::None });
+    }
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #strum_module_path::Localized for #name #ty_generics #where_clause {
+            const LOCALES: &'static [&'static str] = &[#(#locales),*];
+
+            fn to_localized(&self, locale: &str) -> ::core::option::Option<&'static str> {
+                match self {
+                    #(#label_arms),*
+                }
+            }
+
+            fn get_localized_message(&self, locale: &str) -> ::core::option::Option<&'static str> {
+                match self {
+                    #(#message_arms),*
+                }
+            }
+        }
+    })
+}
@@ -9,10 +9,12 @@ pub mod enum_try_as;
 pub mod enum_variant_array;
 pub mod enum_variant_names;
 pub mod from_repr;
+pub mod localized;
 
 mod strings;
 
 pub use self::strings::as_ref_str;
+pub use self::strings::debug;
 pub use self::strings::display;
 pub use self::strings::from_string;
 pub use self::strings::to_string;
@@ -6,7 +6,7 @@ use crate::helpers::{
     non_enum_error, non_single_field_variant_error, HasStrumVariantProperties, HasTypeProperties,
 };
 
-fn get_arms<F>(ast: &DeriveInput, transparent_fn: F) -> syn::Result<Vec<TokenStream>>
+fn get_arms<F>(ast: &DeriveInput, derive: &str, transparent_fn: F) -> syn::Result<Vec<TokenStream>>
 where
     F: Fn(&TokenStream) -> TokenStream,
 {
@@ -27,7 +27,7 @@ where
             continue;
         }
 
-        if let Some(..) = variant_properties.transparent {
+        if variant_properties.transparent.is_some() {
             let arm = super::extract_single_field_variant_and_then(name, variant, |tok| {
                 transparent_fn(tok)
             })
@@ -37,6 +37,8 @@ where
             continue;
         }
 
+        variant_properties.reject_reserved_placeholders(derive)?;
+
         // Look at all the serialize attributes.
         // Use `to_string` attribute (not `as_ref_str` or something) to keep things consistent
         // (i.e. always `enum.as_ref().to_string() == enum.to_string()`).
@@ -69,7 +71,7 @@ where
 pub fn as_ref_str_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
-    let arms = get_arms(ast, |tok| {
+    let arms = get_arms(ast, "AsRefStr", |tok| {
         quote! { ::core::convert::AsRef::<str>::as_ref(#tok) }
     })?;
 
@@ -97,7 +99,11 @@ pub fn as_static_str_inner(
 ) -> syn::Result<TokenStream> {
     let name = &ast.ident;
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
-    let arms = &get_arms(ast, |tok| {
+    let derive = match trait_variant {
+        GenerateTraitVariant::AsStaticStr => "AsStaticStr",
+        GenerateTraitVariant::From => "IntoStaticStr",
+    };
+    let arms = &get_arms(ast, derive, |tok| {
         quote! { ::core::convert::From::from(#tok) }
     })?;
 
@@ -0,0 +1,110 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{parse_quote, Data, DeriveInput, Fields, LitStr};
+
+use crate::helpers::template::{parse_template, Segment};
+use crate::helpers::{
+    non_enum_error, non_single_field_variant_error, HasStrumVariantProperties, HasTypeProperties,
+};
+
+pub fn debug_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let variants = match &ast.data {
+        Data::Enum(v) => &v.variants,
+        _ => return Err(non_enum_error()),
+    };
+
+    // Like `#[derive(Debug)]`, require every type parameter to be `Debug`.
+    let mut generics = ast.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::core::fmt::Debug));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let type_properties = ast.get_type_properties()?;
+
+    let mut arms = Vec::new();
+    for variant in variants {
+        let ident = &variant.ident;
+        let variant_properties = variant.get_variant_properties()?;
+
+        if variant_properties.transparent.is_some() && variant_properties.disabled.is_none() {
+            let arm = super::extract_single_field_variant_and_then(name, variant, |tok| {
+                quote! { ::core::fmt::Debug::fmt(#tok, f) }
+            })
+            .map_err(|_| non_single_field_variant_error("transparent"))?;
+
+            arms.push(arm);
+            continue;
+        }
+
+        // Disabled variants keep the name `#[derive(Debug)]` would give them. Others use what
+        // `Display` writes, unless that interpolates fields, which are listed anyway.
+        let label = if variant_properties.disabled.is_some() {
+            LitStr::new(&ident.unraw().to_string(), ident.span())
+        } else {
+            variant_properties.reject_reserved_placeholders("StrumDebug")?;
+            let output = variant_properties.get_preferred_name(
+                type_properties.case_style,
+                type_properties.prefix.as_ref(),
+                type_properties.suffix.as_ref(),
+            );
+            let interpolated = !matches!(variant.fields, Fields::Unit)
+                && parse_template(&output)?
+                    .iter()
+                    .any(|segment| matches!(segment, Segment::Placeholder { .. }));
+            if interpolated {
+                let mut serialized = variant_properties.clone();
+                serialized.to_string = None;
+                serialized.get_preferred_name(
+                    type_properties.case_style,
+                    type_properties.prefix.as_ref(),
+                    type_properties.suffix.as_ref(),
+                )
+            } else {
+                output
+            }
+        };
+
+        let arm = match &variant.fields {
+            Fields::Unit => quote! { #name::#ident => f.write_str(#label) },
+            Fields::Unnamed(fields) => {
+                let bindings: Vec<_> = (0..fields.unnamed.len())
+                    .map(|index| format_ident!("field{}", index))
+                    .collect();
+                quote! {
+                    #name::#ident(#(ref #bindings),*) => f.debug_tuple(#label)
+                        #(.field(#bindings))*
+                        .finish()
+                }
+            }
+            Fields::Named(fields) => {
+                let bindings: Vec<_> = fields
+                    .named
+                    .iter()
+                    .map(|field| field.ident.as_ref().unwrap())
+                    .collect();
+                let field_names = bindings.iter().map(|binding| binding.unraw().to_string());
+                quote! {
+                    #name::#ident { #(ref #bindings),* } => f.debug_struct(#label)
+                        #(.field(#field_names, #bindings))*
+                        .finish()
+                }
+            }
+        };
+
+        arms.push(arm);
+    }
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Debug for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::result::Result<(), ::core::fmt::Error> {
+                match *self {
+                    #(#arms),*
+                }
+            }
+        }
+    })
+}
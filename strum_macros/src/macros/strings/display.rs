@@ -1,10 +1,14 @@
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
-use syn::{punctuated::Punctuated, Data, DeriveInput, Fields, LitStr, Token};
+use quote::{format_ident, quote};
+use syn::{
+    punctuated::Punctuated, Data, DeriveInput, Fields, LitStr, Member, Path, Token, Variant,
+};
 
+use crate::helpers::template::{parse_template, Segment};
 use crate::helpers::{
     non_enum_error, non_single_field_variant_error, HasStrumVariantProperties, HasTypeProperties,
 };
+use crate::macros::from_repr::{discriminant_const, discriminant_table};
 
 pub fn display_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let name = &ast.ident;
@@ -15,8 +19,10 @@ pub fn display_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     };
 
     let type_properties = ast.get_type_properties()?;
+    let strum_module_path = type_properties.crate_module_path();
 
     let mut arms = Vec::new();
+    let mut uses_discriminant = false;
     for variant in variants {
         let ident = &variant.ident;
         let variant_properties = variant.get_variant_properties()?;
@@ -25,7 +31,18 @@ pub fn display_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
             continue;
         }
 
-        if let Some(..) = variant_properties.transparent {
+        if let Some(alt) = &variant_properties.to_string_alt {
+            if variant_properties.transparent.is_some()
+                || (variant_properties.to_string.is_none() && variant_properties.default.is_some())
+            {
+                return Err(syn::Error::new_spanned(
+                    alt,
+                    "`to_string_alt` can't be used on transparent variants, or default variants without `to_string`",
+                ));
+            }
+        }
+
+        if variant_properties.transparent.is_some() {
             let arm = super::extract_single_field_variant_and_then(name, variant, |tok| {
                 quote! { ::core::fmt::Display::fmt(#tok, f) }
             })
@@ -35,8 +52,17 @@ pub fn display_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
             continue;
         }
 
+        // Variants without their own `to_string` are written with the enum's template, if any.
+        let from_template = variant_properties.to_string.is_none()
+            && variant_properties.default.is_none()
+            && type_properties.to_string_template.is_some();
+        let mut preferred = variant_properties.clone();
+        if from_template {
+            preferred.to_string = type_properties.to_string_template.clone();
+        }
+
         // Look at all the serialize attributes.
-        let output = variant_properties.get_preferred_name(
+        let output = preferred.get_preferred_name(
             type_properties.case_style,
             type_properties.prefix.as_ref(),
             type_properties.suffix.as_ref(),
@@ -89,70 +115,55 @@ pub fn display_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
             continue;
         }
 
-        let arm = match variant.fields {
-            Fields::Named(ref field_names) => {
-                let used_vars = capture_format_string_idents(&output)?;
-                if used_vars.is_empty() {
-                    quote! { #name::#ident #params => ::core::fmt::Display::fmt(#output, f) }
-                } else {
-                    // Create args like 'name = name, age = age' for format macro
-                    let args: Punctuated<_, Token!(,)> = field_names
-                        .named
-                        .iter()
-                        .filter_map(|field| {
-                            let ident = field.ident.as_ref().unwrap();
-                            // Only contain variables that are used in format string
-                            if !used_vars.contains(ident) {
-                                None
-                            } else {
-                                Some(quote! { #ident = #ident })
-                            }
-                        })
-                        .collect();
-
-                    quote! {
-                        #[allow(unused_variables)]
-                        #name::#ident #params => ::core::fmt::Display::fmt(&format_args!(#output, #args), f)
-                    }
-                }
+        let reserved = Reserved {
+            variant_name: variant_properties.get_serialized_name(type_properties.case_style),
+            message: variant_properties.message.as_ref(),
+            discriminant: discriminant_const(ident),
+        };
+        let body = format_output(
+            variant,
+            &output,
+            &reserved,
+            &mut uses_discriminant,
+            &strum_module_path,
+        )
+        .map_err(|e| {
+            if !from_template {
+                return e;
             }
-            Fields::Unnamed(ref unnamed_fields) => {
-                let used_vars = capture_format_strings(&output)?;
-                if used_vars.iter().any(String::is_empty) {
-                    return Err(syn::Error::new_spanned(
-                        &output,
-                        "Empty {} is not allowed; Use manual numbering ({0})",
-                    ));
-                }
-                if used_vars.is_empty() {
-                    quote! { #name::#ident #params => ::core::fmt::Display::fmt(#output, f) }
-                } else {
-                    let args: Punctuated<_, Token!(,)> = unnamed_fields
-                        .unnamed
-                        .iter()
-                        .enumerate()
-                        .map(|(index, field)| {
-                            assert!(field.ident.is_none());
-                            syn::parse_str::<Ident>(format!("field{}", index).as_str()).unwrap()
-                        })
-                        .collect();
-                    quote! {
-                        #[allow(unused_variables)]
-                        #name::#ident #params => ::core::fmt::Display::fmt(&format!(#output, #args), f)
+
+            let mut note = syn::Error::new_spanned(
+                variant,
+                format!(
+                    "`{}` can't be written with `to_string_template`; give it its own `to_string`",
+                    ident
+                ),
+            );
+            note.combine(e);
+            note
+        })?;
+        let body = match &variant_properties.to_string_alt {
+            Some(alt) => {
+                let alt = format_output(
+                    variant,
+                    alt,
+                    &reserved,
+                    &mut uses_discriminant,
+                    &strum_module_path,
+                )?;
+                quote! {
+                    if f.alternate() {
+                        #alt
+                    } else {
+                        #body
                     }
                 }
             }
-            Fields::Unit => {
-                let used_vars = capture_format_strings(&output)?;
-                if !used_vars.is_empty() {
-                    return Err(syn::Error::new_spanned(
-                        &output,
-                        "Unit variants do not support interpolation",
-                    ));
-                }
-
-                quote! { #name::#ident #params => ::core::fmt::Display::fmt(#output, f) }
-            }
+            None => body,
+        };
+        let arm = quote! {
+            #[allow(unused_variables)]
+            #name::#ident #params => #body
         };
 
         arms.push(arm);
@@ -162,10 +173,17 @@ pub fn display_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         arms.push(quote! { _ => panic!("fmt() called on disabled variant.") });
     }
 
+    let constant_defs = if uses_discriminant {
+        discriminant_table(ast)?.constant_defs
+    } else {
+        Vec::new()
+    };
+
     Ok(quote! {
         #[automatically_derived]
         impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
             fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::result::Result<(), ::core::fmt::Error> {
+                #(#constant_defs)*
                 match *self {
                     #(#arms),*
                 }
@@ -174,50 +192,150 @@ pub fn display_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     })
 }
 
-fn capture_format_string_idents(string_literal: &LitStr) -> syn::Result<Vec<Ident>> {
-    capture_format_strings(string_literal)?
-        .into_iter()
-        .map(|ident| {
-            syn::parse_str::<Ident>(ident.as_str()).map_err(|_| {
-                syn::Error::new_spanned(
-                    string_literal,
-                    "Invalid identifier inside format string bracket",
-                )
-            })
-        })
-        .collect()
+/// What the reserved placeholders of a template expand to for one variant.
+struct Reserved<'a> {
+    /// `{__variant}`: the variant's serialized name.
+    variant_name: LitStr,
+    /// `{__message}`: the variant's `message`.
+    message: Option<&'a LitStr>,
+    /// `{__discriminant}`: the constant holding the variant's discriminant.
+    discriminant: Ident,
 }
 
-fn capture_format_strings(string_literal: &LitStr) -> syn::Result<Vec<String>> {
-    // Remove escaped brackets
-    let format_str = string_literal.value().replace("{{", "").replace("}}", "");
+/// Writes `output` into `f`, with the fields of `variant` interpolated. The fields are expected to
+/// be bound by reference, named fields under their own name and unnamed ones as `field0`, `field1`...
+/// `uses_discriminant` is set if the discriminant constants have to be in scope.
+fn format_output(
+    variant: &Variant,
+    output: &LitStr,
+    reserved: &Reserved,
+    uses_discriminant: &mut bool,
+    strum_module_path: &Path,
+) -> syn::Result<TokenStream> {
+    let segments = parse_template(output)?;
+    if !segments
+        .iter()
+        .any(|segment| matches!(segment, Segment::Placeholder { .. }))
+    {
+        return Ok(quote! { ::core::fmt::Display::fmt(#output, f) });
+    }
 
-    let mut new_var_start_index: Option<usize> = None;
-    let mut var_used = Vec::new();
+    // Every placeholder is rewritten to a positional argument, so that field paths and reserved
+    // names can be passed to `format_args!` as arbitrary expressions.
+    let mut format_str = String::new();
+    let mut keys: Vec<&str> = Vec::new();
+    let mut args = Vec::new();
+    for segment in &segments {
+        let (name, spec) = match segment {
+            Segment::Literal(literal) => {
+                format_str.push_str(&literal.replace('{', "{{").replace('}', "}}"));
+                continue;
+            }
+            Segment::Placeholder { name, spec } => (name, spec),
+        };
 
-    for (i, chr) in format_str.bytes().enumerate() {
-        if chr == b'{' {
-            if new_var_start_index.is_some() {
-                return Err(syn::Error::new_spanned(
-                    string_literal,
-                    "Bracket opened without closing previous bracket",
-                ));
+        let index = match keys.iter().position(|key| key == name) {
+            Some(index) => index,
+            None => {
+                args.push(placeholder_arg(
+                    variant,
+                    output,
+                    name,
+                    reserved,
+                    uses_discriminant,
+                )?);
+                keys.push(name);
+                keys.len() - 1
             }
-            new_var_start_index = Some(i);
-            continue;
+        };
+        match spec {
+            Some(spec) => format_str.push_str(&format!("{{{}:{}}}", index, spec)),
+            None => format_str.push_str(&format!("{{{}}}", index)),
         }
+    }
 
-        if chr == b'}' {
-            let start_index = new_var_start_index.take().ok_or(syn::Error::new_spanned(
-                string_literal,
-                "Bracket closed without previous opened bracket",
-            ))?;
+    let format_str = LitStr::new(&format_str, output.span());
+    Ok(quote! {
+        #strum_module_path::_private::pad_fmt(f, format_args!(#format_str, #(#args),*))
+    })
+}
 
-            let inside_brackets = &format_str[start_index + 1..i];
-            let ident_str = inside_brackets.split(":").next().unwrap().trim_end();
-            var_used.push(ident_str.to_owned());
+/// The expression a `{name}` placeholder in `output` stands for: a reserved name, or a field of
+/// `variant` followed by an optional path like `.code` or `.0`.
+fn placeholder_arg(
+    variant: &Variant,
+    output: &LitStr,
+    name: &str,
+    reserved: &Reserved,
+    uses_discriminant: &mut bool,
+) -> syn::Result<TokenStream> {
+    match name {
+        "" => {
+            return Err(syn::Error::new_spanned(
+                output,
+                "Empty {} is not allowed; Use manual numbering ({0})",
+            ))
+        }
+        "__variant" => {
+            let variant_name = &reserved.variant_name;
+            return Ok(quote! { #variant_name });
         }
+        "__message" => {
+            return match reserved.message {
+                Some(message) => Ok(quote! { #message }),
+                None => Err(syn::Error::new_spanned(
+                    output,
+                    format!("`{{__message}}` needs a `message` on `{}`", variant.ident),
+                )),
+            }
+        }
+        "__discriminant" => {
+            *uses_discriminant = true;
+            let discriminant = &reserved.discriminant;
+            return Ok(quote! { #discriminant });
+        }
+        _ => {}
+    }
+
+    if let Fields::Unit = variant.fields {
+        return Err(syn::Error::new_spanned(
+            output,
+            "Unit variants do not support interpolation",
+        ));
+    }
+
+    let mut path = name.split('.');
+    let field = path.next().unwrap();
+    let binding = match &variant.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .filter_map(|field| field.ident.clone())
+            .find(|ident| ident == field),
+        Fields::Unnamed(fields) => field
+            .parse::<usize>()
+            .ok()
+            .filter(|index| *index < fields.unnamed.len())
+            .map(|index| format_ident!("field{}", index)),
+        Fields::Unit => None,
     }
+    .ok_or_else(|| {
+        syn::Error::new_spanned(
+            output,
+            format!("`{{{}}}` doesn't name a field of `{}`", name, variant.ident),
+        )
+    })?;
+
+    let members = path
+        .map(|member| {
+            syn::parse_str::<Member>(member).map_err(|_| {
+                syn::Error::new_spanned(
+                    output,
+                    format!("Invalid field path `{}` inside format string bracket", name),
+                )
+            })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
 
-    Ok(var_used)
+    Ok(quote! { #binding #(.#members)* })
 }
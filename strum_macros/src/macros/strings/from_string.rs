@@ -1,11 +1,16 @@
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{parse_quote, Data, DeriveInput, Fields, Path};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_quote, Data, DeriveInput, Fields, Lifetime, LitByteStr, LitStr, Path, Type, Variant,
+};
 
+use crate::helpers::decision_tree::{decision_tree, Entry};
+use crate::helpers::template::{parse_template, Segment};
 use crate::helpers::{
     missing_parse_err_attr_error, non_enum_error, occurrence_error, HasInnerVariantProperties,
     HasStrumVariantProperties, HasTypeProperties,
 };
+use crate::macros::from_repr::{discriminant_table, DiscriminantTable};
 
 pub fn from_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let name = &ast.ident;
@@ -18,28 +23,118 @@ pub fn from_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let type_properties = ast.get_type_properties()?;
     let strum_module_path = type_properties.crate_module_path();
 
+    let not_found = if type_properties.suggest.is_some() {
+        quote! { #strum_module_path::VariantNotFound::with_suggestions::<SUGGEST_WIDTH>(input, EXPECTED) }
+    } else {
+        quote! { #strum_module_path::VariantNotFound::new(input, EXPECTED) }
+    };
     let mut default_kw = None;
+    let mut same_default = None;
+    let mut default_field_ty = None;
+    let mut err_fn = None;
     let (mut default_err_ty, mut default) = match (
         type_properties.parse_err_ty,
         type_properties.parse_err_fn,
+        type_properties.parse_err_context_fn,
     ) {
-        (None, None) => (
+        (None, None, None) => (
             quote! { #strum_module_path::ParseError },
-            quote! { ::core::result::Result::Err(#strum_module_path::ParseError::VariantNotFound) },
+            quote! {
+                ::core::result::Result::Err(
+                    #strum_module_path::ParseError::VariantNotFound(#not_found),
+                )
+            },
         ),
-        (Some(ty), Some(f)) => {
+        (Some(_), Some(_), None) if type_properties.suggest.is_some() => {
+            return Err(syn::Error::new_spanned(
+                type_properties.suggest,
+                "`suggest` can't be combined with `parse_err_ty` and `parse_err_fn`",
+            ));
+        }
+        (Some(ty), Some(path), None) => {
+            let ty_path: Path = parse_quote!(#ty);
+            let f = ErrFn::Input(path);
+            let err = f.call(quote!(input), &strum_module_path);
+            err_fn = Some(f);
+
+            (
+                quote! { #ty_path },
+                quote! { ::core::result::Result::Err(#err) },
+            )
+        }
+        (Some(ty), None, Some(path)) => {
             let ty_path: Path = parse_quote!(#ty);
-            let fn_path: Path = parse_quote!(#f);
+            let f = ErrFn::Context {
+                path,
+                enum_name: name.to_string(),
+                suggest: type_properties.suggest.is_some(),
+            };
+            let err = f.call(quote!(input), &strum_module_path);
+            err_fn = Some(f);
 
             (
                 quote! { #ty_path },
-                quote! { ::core::result::Result::Err(#fn_path(s)) },
+                quote! { ::core::result::Result::Err(#err) },
             )
         }
         _ => return Err(missing_parse_err_attr_error()),
     };
+    // The prefix and suffix are stripped from the input before matching, but the accepted
+    // strings are listed with them.
+    let prefix = type_properties.prefix.as_ref();
+    let suffix = type_properties.suffix.as_ref();
+    let with_affixes = |serialization: &LitStr| {
+        let mut value = serialization.value();
+        if let Some(prefix) = prefix {
+            value.insert_str(0, &prefix.value());
+        }
+
+        if let Some(suffix) = suffix {
+            value.push_str(&suffix.value());
+        }
+
+        LitStr::new(&value, serialization.span())
+    };
+    // Normalizations with `trim` strip the prefix and suffix from the trimmed input instead, so
+    // that whitespace around them is ignored too. Evaluates to `None` if a required one is missing.
+    let optional_affixes = type_properties.optional_affixes;
+    let strip_affixes_from = |input: TokenStream| {
+        let strip = |method: TokenStream, affix: &LitStr| {
+            if optional_affixes {
+                quote! { let s = s.#method(#affix).unwrap_or(s); }
+            } else {
+                quote! { let s = s.#method(#affix)?; }
+            }
+        };
+        let strip_prefix = prefix.map(|prefix| strip(quote!(strip_prefix), prefix));
+        let strip_suffix = suffix.map(|suffix| strip(quote!(strip_suffix), suffix));
+        quote! {
+            (|| {
+                let s = #input;
+                #strip_prefix
+                #strip_suffix
+                ::core::option::Option::Some(s)
+            })()
+        }
+    };
+
     let mut phf_exact_match_arms = Vec::new();
+    let mut phf_folded_match_arms = Vec::new();
+    let mut phf_folded_keys: Vec<String> = Vec::new();
     let mut standard_match_arms = Vec::new();
+    let mut byte_match_arms = Vec::new();
+    let mut const_arms = Vec::new();
+    let mut tree_entries = Vec::new();
+    let mut byte_tree_entries = Vec::new();
+    let mut expected = Vec::new();
+    let mut field_patterns = Vec::new();
+    let mut normalized_groups: Vec<NormalizedGroup> = Vec::new();
+    let mut parsed: Vec<Parsed> = Vec::new();
+    let mut abbreviations: Vec<(String, bool, &Ident, TokenStream)> = Vec::new();
+    let mut deprecated_checks = Vec::new();
+    let mut prefix_entries: Vec<(LitStr, bool, TokenStream)> = Vec::new();
+    let mut patterns: Vec<(String, &Ident)> = Vec::new();
+    let mut pattern_matches = Vec::new();
     for variant in variants {
         let ident = &variant.ident;
         let variant_properties = variant.get_variant_properties()?;
@@ -53,19 +148,57 @@ pub fn from_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
                 return Err(occurrence_error(fst_kw, kw, "default"));
             }
 
+            if type_properties.const_from_str.is_some() {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "`const_from_str` can't be combined with a default variant",
+                ));
+            }
+
+            if let Some(pattern) = variant_properties.patterns.first() {
+                return Err(syn::Error::new_spanned(
+                    pattern,
+                    "`pattern` can't be used on the default variant",
+                ));
+            }
+
             default_kw = Some(kw);
+
+            // Lists compare the values of the default variant, not just the variant.
+            same_default = match &variant.fields {
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => Some(quote! {
+                    (#name::#ident(a), #name::#ident(b)) => a == b,
+                }),
+                Fields::Named(fields) if fields.named.len() == 1 => {
+                    let field_name = fields.named[0].ident.as_ref().unwrap();
+                    Some(quote! {
+                        (#name::#ident { #field_name: a }, #name::#ident { #field_name: b }) => a == b,
+                    })
+                }
+                _ => None,
+            };
+
+            if variant_properties.default_from_str.is_some() {
+                // The inner type's parser can fail, so errors are still possible.
+                default = default_from_str(name, variant, &err_fn, &strum_module_path)?;
+                continue;
+            }
+
             default_err_ty = quote! { #strum_module_path::ParseError };
+            err_fn = None;
 
             match &variant.fields {
                 Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    default_field_ty = Some(&fields.unnamed[0].ty);
                     default = quote! {
-                        ::core::result::Result::Ok(#name::#ident(s.into()))
+                        ::core::result::Result::Ok(#name::#ident(input.into()))
                     };
                 }
                 Fields::Named(ref f) if f.named.len() == 1 => {
+                    default_field_ty = Some(&f.named[0].ty);
                     let field_name = f.named.last().unwrap().ident.as_ref().unwrap();
                     default = quote! {
-                        ::core::result::Result::Ok(#name::#ident { #field_name : s.into() } )
+                        ::core::result::Result::Ok(#name::#ident { #field_name : input.into() } )
                     };
                 }
                 _ => {
@@ -79,6 +212,13 @@ pub fn from_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
             continue;
         }
 
+        if type_properties.const_from_str.is_some() && !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "`const_from_str` only supports unit variants",
+            ));
+        }
+
         let params = match &variant.fields {
             Fields::Unit => quote! {},
             Fields::Unnamed(fields) => {
@@ -116,39 +256,310 @@ pub fn from_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         let is_ascii_case_insensitive = variant_properties
             .ascii_case_insensitive
             .unwrap_or(type_properties.ascii_case_insensitive);
+        let normalize = Normalize {
+            trim: variant_properties.trim.unwrap_or(type_properties.trim),
+            case_insensitive: variant_properties
+                .case_insensitive
+                .unwrap_or(type_properties.case_insensitive),
+            separator_insensitive: variant_properties
+                .separator_insensitive
+                .unwrap_or(type_properties.separator_insensitive),
+        };
+
+        variant_properties.reject_reserved_placeholders("EnumString")?;
+
+        // Templates with placeholders are matched after all the exact serializations.
+        let pattern = match &variant_properties.to_string {
+            Some(to_string)
+                if (variant_properties.parse_fields.is_some() || type_properties.parse_fields)
+                    && !matches!(variant.fields, Fields::Unit) =>
+            {
+                let segments = parse_template(to_string)?;
+                if segments
+                    .iter()
+                    .any(|s| matches!(s, Segment::Placeholder { .. }))
+                {
+                    field_patterns.push((variant, to_string.clone(), segments));
+                    Some(to_string)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
 
         // If we don't have any custom variants, add the default serialized name.
-        for serialization in variant_properties.get_serializations(type_properties.case_style) {
-            if type_properties.use_phf {
-                phf_exact_match_arms.push(quote! { #serialization => #name::#ident #params, });
+        let serializations = variant_properties.get_serializations(type_properties.case_style);
+        let case_style_serializations: Vec<_> = variant_properties
+            .get_case_style_serializations(&type_properties.parse_case_styles)
+            .into_iter()
+            .filter(|c| !serializations.iter().any(|s| s.value() == c.value()))
+            .collect();
+        let deprecated_aliases = variant_properties.deprecated_aliases.iter().cloned();
+        let serializations = serializations
+            .into_iter()
+            .map(|s| (s, false, false))
+            .chain(
+                case_style_serializations
+                    .into_iter()
+                    .map(|s| (s, true, false)),
+            )
+            .chain(deprecated_aliases.map(|s| (s, false, true)));
+        for (serialization, from_case_style, deprecated) in serializations {
+            if pattern.map(LitStr::value) == Some(serialization.value()) {
+                continue;
+            }
 
+            // Only the first of two matching arms could ever be reached.
+            let value = serialization.value();
+            if parsed.iter().any(|p| p.ident == ident && p.value == value) {
+                continue;
+            }
+
+            // Two variants collide if either one accepts the other's string as its input.
+            let this = Parsed {
+                value,
+                span: serialization.span(),
+                ident,
+                from_case_style,
+                ascii_case_insensitive: is_ascii_case_insensitive,
+                normalize,
+            };
+            let collision = parsed.iter().find_map(|other| {
+                if other.ident == ident {
+                    None
+                } else if other.accepts(&this.value) {
+                    Some((other, &this.value))
+                } else if this.accepts(&other.value) {
+                    Some((other, &other.value))
+                } else {
+                    None
+                }
+            });
+            if let Some((other, shared)) = collision {
+                let reason = if from_case_style || other.from_case_style {
+                    " once other case styles are accepted"
+                } else if other.value == this.value {
+                    ""
+                } else if normalize != Normalize::default()
+                    || other.normalize != Normalize::default()
+                {
+                    " once normalized"
+                } else {
+                    " when ignoring ASCII case"
+                };
+                let mut e = syn::Error::new(
+                    serialization.span(),
+                    format!(
+                        "`{}` and `{}` both parse from \"{}\"{}",
+                        other.ident, ident, shared, reason
+                    ),
+                );
+                e.combine(syn::Error::new(
+                    other.span,
+                    format_args!("`{}` parses from \"{}\" here", other.ident, other.value),
+                ));
+                return Err(e);
+            }
+
+            parsed.push(this);
+            if deprecated {
+                // Aliases still parse, but aren't advertised in errors or completions.
+                let normalized = normalize.apply(&serialization.value());
+                let literal = LitStr::new(&normalized, serialization.span());
+                let compare = if is_ascii_case_insensitive && !normalize.case_insensitive {
+                    quote! { s.eq_ignore_ascii_case(#literal) }
+                } else {
+                    quote! { s == #literal }
+                };
+                let matches = if normalize != Normalize::default() {
+                    let Normalize {
+                        trim,
+                        case_insensitive,
+                        separator_insensitive,
+                    } = normalize;
+                    let max_len = normalized.len();
+                    let source = if trim {
+                        strip_affixes_from(quote!(input.trim()))
+                    } else {
+                        quote!(::core::option::Option::Some(s))
+                    };
+                    quote! {
+                        {
+                            let normalize = #strum_module_path::_private::Normalize {
+                                trim: #trim,
+                                case_insensitive: #case_insensitive,
+                                separator_insensitive: #separator_insensitive,
+                            };
+                            let mut buf = [0u8; #max_len];
+                            match #source.and_then(|s| normalize.apply(s, &mut buf)) {
+                                ::core::option::Option::Some(s) => #compare,
+                                ::core::option::Option::None => false,
+                            }
+                        }
+                    }
+                } else {
+                    compare
+                };
+                deprecated_checks.push(quote! {
+                    if ::core::matches!(value, #name::#ident { .. }) && #matches {
+                        return ::core::option::Option::Some(#serialization);
+                    }
+                });
+            } else if !from_case_style {
+                let serialization = with_affixes(&serialization);
+                abbreviations.push((
+                    serialization.value(),
+                    is_ascii_case_insensitive,
+                    ident,
+                    params.clone(),
+                ));
+                expected.push(serialization);
+            }
+
+            // The whole strings `const_from_str` and `parse_prefix` compare the input to.
+            let affixed = with_affixes(&serialization);
+            let bare = (type_properties.optional_affixes
+                && affixed.value() != serialization.value())
+            .then(|| serialization.clone());
+            let whole: Vec<LitStr> = ::core::iter::once(affixed).chain(bare).collect();
+
+            for serialization in &whole {
+                prefix_entries.push((
+                    serialization.clone(),
+                    is_ascii_case_insensitive,
+                    quote! { #name::#ident #params },
+                ));
+            }
+
+            if let Some(kw) = type_properties.parse_prefix {
+                if normalize != Normalize::default() {
+                    return Err(syn::Error::new_spanned(
+                        kw,
+                        "`parse_prefix` doesn't support `trim`, `case_insensitive` or `separator_insensitive`",
+                    ));
+                }
+            }
+
+            if let Some(kw) = type_properties.const_from_str {
+                if normalize != Normalize::default() {
+                    return Err(syn::Error::new_spanned(
+                        kw,
+                        "`const_from_str` doesn't support `trim`, `case_insensitive` or `separator_insensitive`",
+                    ));
+                }
+
+                for serialization in &whole {
+                    const_arms.push(quote! {
+                        if #strum_module_path::_private::const_eq(s, #serialization, #is_ascii_case_insensitive) {
+                            return ::core::option::Option::Some(#name::#ident);
+                        }
+                    });
+                }
+            }
+
+            if normalize != Normalize::default() {
+                // The input is normalized once per group, so each group is its own match.
+                let group = match normalized_groups
+                    .iter()
+                    .position(|g| g.normalize == normalize)
+                {
+                    Some(index) => &mut normalized_groups[index],
+                    None => {
+                        normalized_groups.push(NormalizedGroup {
+                            normalize,
+                            arms: Vec::new(),
+                            max_len: 0,
+                        });
+                        normalized_groups.last_mut().unwrap()
+                    }
+                };
+
+                let normalized = normalize.apply(&serialization.value());
+                group.max_len = group.max_len.max(normalized.len());
+                let normalized = LitStr::new(&normalized, serialization.span());
+                group.arms.push(if is_ascii_case_insensitive && !normalize.case_insensitive {
+                    quote! { s if s.eq_ignore_ascii_case(#normalized) => return ::core::result::Result::Ok(#name::#ident #params), }
+                } else {
+                    quote! { #normalized => return ::core::result::Result::Ok(#name::#ident #params), }
+                });
+            } else if type_properties.use_phf {
                 if is_ascii_case_insensitive {
-                    // Store the lowercase and UPPERCASE variants in the phf map to capture
-                    let ser_string = serialization.value();
+                    // The input is lowercased once, then looked up among the lowercased keys.
+                    let lower = serialization.value().to_ascii_lowercase();
+                    if phf_folded_keys.contains(&lower) {
+                        continue;
+                    }
 
-                    let lower =
-                        syn::LitStr::new(&ser_string.to_ascii_lowercase(), serialization.span());
-                    let upper =
-                        syn::LitStr::new(&ser_string.to_ascii_uppercase(), serialization.span());
-                    phf_exact_match_arms.push(quote! { #lower => #name::#ident #params, });
-                    phf_exact_match_arms.push(quote! { #upper => #name::#ident #params, });
-                    standard_match_arms.push(quote! { s if s.eq_ignore_ascii_case(#serialization) => #name::#ident #params, });
+                    let key = LitStr::new(&lower, serialization.span());
+                    phf_folded_match_arms.push(quote! { #key => #name::#ident #params, });
+                    phf_folded_keys.push(lower);
+                } else {
+                    phf_exact_match_arms.push(quote! { #serialization => #name::#ident #params, });
                 }
+            } else if type_properties.use_decision_tree {
+                let value = quote! { ::core::result::Result::Ok(#name::#ident #params) };
+                tree_entries.push(Entry {
+                    bytes: serialization.value().into_bytes(),
+                    ascii_case_insensitive: is_ascii_case_insensitive,
+                    value: value.clone(),
+                });
+                byte_tree_entries.push(Entry {
+                    bytes: with_affixes(&serialization).value().into_bytes(),
+                    ascii_case_insensitive: is_ascii_case_insensitive,
+                    value,
+                });
             } else {
                 standard_match_arms.push(if !is_ascii_case_insensitive {
                     quote! { #serialization => #name::#ident #params, }
                 } else {
                     quote! { s if s.eq_ignore_ascii_case(#serialization) => #name::#ident #params, }
                 });
+
+                let bytes = with_affixes(&serialization);
+                let bytes = LitByteStr::new(bytes.value().as_bytes(), bytes.span());
+                byte_match_arms.push(if !is_ascii_case_insensitive {
+                    quote! { #bytes => return ::core::result::Result::Ok(#name::#ident #params), }
+                } else {
+                    quote! { s if s.eq_ignore_ascii_case(#bytes) => return ::core::result::Result::Ok(#name::#ident #params), }
+                });
             }
         }
+
+        // Patterns are tried in declaration order, once no exact serialization matched.
+        for pattern in &variant_properties.patterns {
+            if type_properties.const_from_str.is_some() {
+                return Err(syn::Error::new_spanned(
+                    pattern,
+                    "`const_from_str` doesn't support `pattern`",
+                ));
+            }
+
+            if let Some((_, other)) = patterns.iter().find(|(p, _)| p == &pattern.value()) {
+                return Err(syn::Error::new_spanned(
+                    pattern,
+                    format!(
+                        "`{}` already matches the pattern \"{}\"",
+                        other,
+                        pattern.value()
+                    ),
+                ));
+            }
+
+            // Globs aren't valid input themselves, so they aren't listed as expected strings.
+            patterns.push((pattern.value(), ident));
+            pattern_matches.push(quote! {
+                if #strum_module_path::_private::glob(#pattern, s, #is_ascii_case_insensitive) {
+                    return ::core::result::Result::Ok(#name::#ident #params);
+                }
+            });
+        }
     }
 
-    let phf_body = if phf_exact_match_arms.is_empty() {
+    let phf_exact = if phf_exact_match_arms.is_empty() {
         quote!()
     } else {
         quote! {
-            use #strum_module_path::_private_phf_reexport_for_macro_if_phf_feature as phf;
             static PHF: phf::Map<&'static str, #name> = phf::phf_map! {
                 #(#phf_exact_match_arms)*
             };
@@ -157,62 +568,1132 @@ pub fn from_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
             }
         }
     };
+    let phf_folded = if phf_folded_match_arms.is_empty() {
+        quote!()
+    } else {
+        // Lowercasing can't change the length, so the input must fit the longest key.
+        let max_len = phf_folded_keys.iter().map(String::len).max().unwrap();
+        quote! {
+            static PHF_FOLDED: phf::Map<&'static str, #name> = phf::phf_map! {
+                #(#phf_folded_match_arms)*
+            };
+            let mut buf = [0u8; #max_len];
+            if let Some(folded) = #strum_module_path::_private::ascii_lowercase(s, &mut buf) {
+                if let Some(value) = PHF_FOLDED.get(folded).cloned() {
+                    return ::core::result::Result::Ok(value);
+                }
+            }
+        }
+    };
+    let phf_body = if phf_exact_match_arms.is_empty() && phf_folded_match_arms.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            use #strum_module_path::_private_phf_reexport_for_macro_if_phf_feature as phf;
+            #phf_exact
+            #phf_folded
+        }
+    };
+
+    // The suggestion buffers need room for the longest serialization plus one.
+    let suggest_width = type_properties.suggest.map(|_| {
+        let width = expected
+            .iter()
+            .map(|s| s.value().chars().count() + 1)
+            .max()
+            .unwrap_or(1);
+        quote! { const SUGGEST_WIDTH: usize = #width; }
+    });
+
+    // Once a template matched, a field that fails to parse is reported instead of falling through.
+    let invalid_field = match &err_fn {
+        Some(f) => f.call(quote!(input), &strum_module_path),
+        _ => quote! {
+            #strum_module_path::ParseError::InvalidField(
+                #strum_module_path::InvalidField::new(value, variant, field),
+            )
+        },
+    };
+    let field_patterns = field_patterns
+        .into_iter()
+        .map(|(variant, to_string, segments)| {
+            field_pattern(name, variant, &to_string, &segments, &invalid_field)
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let normalized_matches: Vec<(bool, TokenStream)> = normalized_groups.iter().map(|group| {
+        let NormalizedGroup {
+            normalize:
+                Normalize {
+                    trim,
+                    case_insensitive,
+                    separator_insensitive,
+                },
+            arms,
+            max_len,
+        } = group;
+
+        let source = if *trim {
+            strip_affixes_from(quote!(input.trim()))
+        } else {
+            quote!(::core::option::Option::Some(s))
+        };
+        let matches = quote! {
+            {
+                let normalize = #strum_module_path::_private::Normalize {
+                    trim: #trim,
+                    case_insensitive: #case_insensitive,
+                    separator_insensitive: #separator_insensitive,
+                };
+                let mut buf = [0u8; #max_len];
+                if let ::core::option::Option::Some(s) = #source.and_then(|s| normalize.apply(s, &mut buf)) {
+                    match s {
+                        #(#arms)*
+                        _ => {}
+                    }
+                }
+            }
+        };
+        (*trim, matches)
+    }).collect();
+    // Only the groups that trim can still match an input whose untrimmed affixes didn't.
+    let trimmed_matches = normalized_matches
+        .iter()
+        .filter(|(trim, _)| *trim)
+        .map(|(_, matches)| matches);
+    let normalized_matches = normalized_matches.iter().map(|(_, matches)| matches);
+
+    let (abbreviations, completions) = if type_properties.unique_prefix {
+        let ambiguous = match (&err_fn, default_kw) {
+            (None, None) => Some(quote! { #strum_module_path::ParseError::Ambiguous }),
+            _ => None,
+        };
+        let (abbreviations, completions) =
+            unique_prefix(name, abbreviations, ambiguous, &strum_module_path);
+        let completions = quote! {
+            #[allow(clippy::use_self)]
+            #[automatically_derived]
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// The accepted strings that start with `prefix`, in lexicographic order.
+                pub fn completions(prefix: &str) -> impl ::core::iter::Iterator<Item = &'static str> {
+                    #completions
+                }
+            }
+        };
+        (Some(abbreviations), Some(completions))
+    } else {
+        (None, None)
+    };
+
+    // Abbreviations are compared to the whole input, including the prefix and suffix.
+    let default = quote! {
+        #abbreviations
+        #default
+    };
+
+    let strip_affixes = match (prefix, suffix) {
+        (None, None) => quote! {},
+        _ if type_properties.optional_affixes => {
+            let strip_prefix =
+                prefix.map(|prefix| quote! { let s = s.strip_prefix(#prefix).unwrap_or(s); });
+            let strip_suffix =
+                suffix.map(|suffix| quote! { let s = s.strip_suffix(#suffix).unwrap_or(s); });
+            quote! {
+                #strip_prefix
+                #strip_suffix
+            }
+        }
+        _ => {
+            let stripped = strip_affixes_from(quote!(s));
+            quote! {
+                let s = match #stripped {
+                    ::core::option::Option::Some(s) => s,
+                    ::core::option::Option::None => return {
+                        #(#trimmed_matches)*
+                        #default
+                    },
+                };
+            }
+        }
+    };
+
+    // Numbers are looked up among the discriminants, the same way as in `from_repr`.
+    let repr_match = match type_properties.parse_repr {
+        Some(kw) if type_properties.const_from_str.is_some() => {
+            return Err(syn::Error::new_spanned(
+                kw,
+                "`const_from_str` doesn't support `parse_repr`",
+            ));
+        }
+        Some(_) => {
+            let DiscriminantTable {
+                discriminant_type,
+                constant_defs,
+                arms,
+                ..
+            } = discriminant_table(ast)?;
+            quote! {
+                if let ::core::result::Result::Ok(discriminant) = s.parse::<#discriminant_type>() {
+                    #(#constant_defs)*
+                    let value: ::core::option::Option<Self> = match discriminant {
+                        #(#arms),*
+                    };
+                    if let ::core::option::Option::Some(value) = value {
+                        return ::core::result::Result::Ok(value);
+                    }
+                }
+            }
+        }
+        None => quote!(),
+    };
 
-    let standard_match_body = if standard_match_arms.is_empty() {
+    let default = quote! {
+        #(#normalized_matches)*
+        #(#field_patterns)*
+        #repr_match
+        #(#pattern_matches)*
+        #default
+    };
+
+    let standard_match_body = if !tree_entries.is_empty() {
+        let tree = decision_tree(&tree_entries);
+        quote! {
+            {
+                let bytes = s.as_bytes();
+                #tree
+            }
+            #default
+        }
+    } else if standard_match_arms.is_empty() {
         default
     } else {
         quote! {
             ::core::result::Result::Ok(match s {
                 #(#standard_match_arms)*
-                _ => return #default,
+                _ => return { #default },
             })
         }
     };
 
-    let from_str = quote! {
-        #[allow(clippy::use_self)]
-        #[automatically_derived]
-        impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
-            type Err = #default_err_ty;
+    let body = quote! {
+        #[allow(dead_code)]
+        const EXPECTED: &[&str] = &[#(#expected),*];
+        #suggest_width
+        let input = s;
+        #strip_affixes
+        #phf_body
+        #standard_match_body
+    };
 
-            #[inline]
-            fn from_str(s: &str) -> ::core::result::Result< #name #ty_generics , <Self as ::core::str::FromStr>::Err> {
-                #phf_body
-                #standard_match_body
+    // `FromStr` can't return anything borrowing from its input, so a default variant that does
+    // is only built by `TryFrom<&'a str>`.
+    let lifetimes: Vec<&Lifetime> = ast.generics.lifetimes().map(|l| &l.lifetime).collect();
+    let borrows = match default_field_ty {
+        Some(ty) if lifetimes.iter().any(|l| mentions_lifetime(ty, l)) => {
+            if lifetimes.len() > 1 {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    "The default variant can only borrow from the input if the enum has a single lifetime parameter",
+                ));
+            }
+
+            true
+        }
+        _ => false,
+    };
+
+    let parse_borrowed = match (type_properties.parse_borrowed, lifetimes.as_slice()) {
+        (None, _) => quote!(),
+        (Some(_), [lifetime]) => {
+            let body = if borrows {
+                quote! { <Self as ::core::convert::TryFrom<&#lifetime str>>::try_from(s) }
+            } else {
+                quote! { ::core::str::FromStr::from_str(s) }
+            };
+            quote! {
+                #[allow(clippy::use_self)]
+                #[automatically_derived]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// Parses `s` the same way `from_str` does, but the default variant may
+                    /// borrow from it.
+                    pub fn parse_borrowed(
+                        s: &#lifetime str,
+                    ) -> ::core::result::Result<Self, #default_err_ty> {
+                        #body
+                    }
+                }
+            }
+        }
+        (Some(kw), _) => {
+            return Err(syn::Error::new_spanned(
+                kw,
+                "`parse_borrowed` requires the enum to have a single lifetime parameter",
+            ));
+        }
+    };
+
+    let from_str = if let (true, [lifetime]) = (borrows, lifetimes.as_slice()) {
+        quote! {
+            #[allow(clippy::use_self)]
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::TryFrom<&#lifetime str> for #name #ty_generics #where_clause {
+                type Error = #default_err_ty;
+
+                #[inline]
+                fn try_from(s: &#lifetime str) -> ::core::result::Result<Self, #default_err_ty> {
+                    #body
+                }
+            }
+        }
+    } else {
+        quote! {
+            #[allow(clippy::use_self)]
+            #[automatically_derived]
+            impl #impl_generics ::core::str::FromStr for #name #ty_generics #where_clause {
+                type Err = #default_err_ty;
+
+                #[inline]
+                fn from_str(s: &str) -> ::core::result::Result< #name #ty_generics , <Self as ::core::str::FromStr>::Err> {
+                    #body
+                }
+            }
+        }
+    };
+    // The plain serializations are compared as bytes, everything else goes through `from_str`.
+    let byte_match = if !byte_tree_entries.is_empty() {
+        decision_tree(&byte_tree_entries)
+    } else if byte_match_arms.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            match bytes {
+                #(#byte_match_arms)*
+                _ => {}
             }
         }
     };
-    let try_from_str = try_from_str(
-        name,
-        &impl_generics,
-        &ty_generics,
-        where_clause,
-        &default_err_ty,
-    );
+
+    // Input that isn't UTF-8 can't match any serialization, so it gets the not-found error even
+    // if there is a default variant.
+    let not_found = match &err_fn {
+        Some(f) => f.call(quote!(input), &strum_module_path),
+        None => quote! { #strum_module_path::ParseError::VariantNotFound(#not_found) },
+    };
+    let not_utf8 = quote! {
+        {
+            #[allow(dead_code)]
+            const EXPECTED: &[&str] = &[#(#expected),*];
+            #suggest_width
+            #not_found
+        }
+    };
+
+    let try_from_str = if borrows {
+        if let Some(kw) = type_properties.extra_try_from {
+            return Err(syn::Error::new_spanned(
+                kw,
+                "`extra_try_from` can't be used when the default variant borrows from the input",
+            ));
+        }
+
+        quote!()
+    } else {
+        let extra_try_from = type_properties.extra_try_from.map(|_| {
+            extra_try_from(
+                name,
+                &ast.generics,
+                &default_err_ty,
+                &byte_match,
+                &not_utf8,
+                &strum_module_path,
+            )
+        });
+
+        quote! {
+            #[allow(clippy::use_self)]
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::TryFrom<&str> for #name #ty_generics #where_clause {
+                type Error = #default_err_ty;
+
+                #[inline]
+                fn try_from(s: &str) -> ::core::result::Result< #name #ty_generics , <Self as ::core::convert::TryFrom<&str>>::Error> {
+                    ::core::str::FromStr::from_str(s)
+                }
+            }
+
+            #extra_try_from
+        }
+    };
+
+    let const_from_str = type_properties.const_from_str.map(|_| {
+        let panic_message = LitStr::new(
+            &format!("no variant of `{}` matches the string", name),
+            name.span(),
+        );
+        quote! {
+            #[allow(clippy::use_self)]
+            #[automatically_derived]
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Like `from_str`, but usable in `const` contexts. Returns `None` if no variant matches.
+                pub const fn try_from_str_const(s: &str) -> ::core::option::Option<Self> {
+                    #(#const_arms)*
+                    ::core::option::Option::None
+                }
+
+                /// Like `from_str`, but usable in `const` contexts. Panics if no variant matches, which
+                /// is a compile error when evaluated in a `const`.
+                pub const fn from_str_const(s: &str) -> Self {
+                    match Self::try_from_str_const(s) {
+                        ::core::option::Option::Some(value) => value,
+                        ::core::option::Option::None => ::core::panic!(#panic_message),
+                    }
+                }
+            }
+        }
+    });
+
+    let duplicate = match &err_fn {
+        // The context is built from the same constants as in `from_str`.
+        Some(f @ ErrFn::Context { .. }) => {
+            let err = f.call(quote!(s), &strum_module_path);
+            quote! {
+                {
+                    const EXPECTED: &[&str] = &[#(#expected),*];
+                    #suggest_width
+                    #err
+                }
+            }
+        }
+        Some(f) => f.call(quote!(s), &strum_module_path),
+        _ => quote! {
+            #strum_module_path::ParseError::Duplicate(#strum_module_path::Duplicate::new(s))
+        },
+    };
+    let parse_list = type_properties.parse_list.map(|_| {
+        quote! {
+            #[allow(clippy::use_self)]
+            #[automatically_derived]
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Parses every `sep` separated item of `s` the same way `from_str` does.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `sep` is empty.
+                pub fn parse_list<'_derivative_strum>(
+                    s: &'_derivative_strum str,
+                    sep: &'_derivative_strum str,
+                ) -> #strum_module_path::ParseList<'_derivative_strum, Self> {
+                    let same: fn(&Self, &Self) -> bool = |a, b| match (a, b) {
+                        #same_default
+                        _ => ::core::mem::discriminant(a) == ::core::mem::discriminant(b),
+                    };
+                    let duplicate: fn(&str) -> #default_err_ty = |s| #duplicate;
+                    #strum_module_path::ParseList::new(s, sep, same, duplicate)
+                }
+
+                /// Parses every `sep` separated item of `s` and collects them, stopping at the first
+                /// error.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `sep` is empty.
+                pub fn collect_list<C: ::core::iter::FromIterator<Self>>(
+                    s: &str,
+                    sep: &str,
+                ) -> ::core::result::Result<C, #default_err_ty> {
+                    Self::parse_list(s, sep).collect()
+                }
+            }
+        }
+    });
+
+    let parse_with_info = type_properties.parse_with_info.then(|| {
+        // Only the deprecated aliases are checked again, once `from_str` has accepted the input.
+        let deprecated_alias = if deprecated_checks.is_empty() {
+            quote! { ::core::option::Option::None }
+        } else {
+            let strip_prefix =
+                prefix.map(|prefix| quote! { let s = s.strip_prefix(#prefix).unwrap_or(s); });
+            let strip_suffix =
+                suffix.map(|suffix| quote! { let s = s.strip_suffix(#suffix).unwrap_or(s); });
+            quote! {
+                (|| {
+                    #[allow(unused_variables)]
+                    let input = s;
+                    #strip_prefix
+                    #strip_suffix
+                    #(#deprecated_checks)*
+                    ::core::option::Option::None
+                })()
+            }
+        };
+        let (input_ty, parse) = match lifetimes.as_slice() {
+            [lifetime] if borrows => (
+                quote! { &#lifetime str },
+                quote! { <Self as ::core::convert::TryFrom<&#lifetime str>>::try_from(s) },
+            ),
+            _ => (
+                quote! { &str },
+                quote! { <Self as ::core::str::FromStr>::from_str(s) },
+            ),
+        };
+        quote! {
+            #[allow(clippy::use_self)]
+            #[automatically_derived]
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Parses `s` the same way `from_str` does, and also tells whether it was one of the
+                /// variant's deprecated aliases.
+                pub fn parse_with_info(
+                    s: #input_ty,
+                ) -> ::core::result::Result<(Self, #strum_module_path::ParseInfo), #default_err_ty> {
+                    let value = #parse?;
+                    let deprecated_alias = #deprecated_alias;
+                    ::core::result::Result::Ok((value, #strum_module_path::ParseInfo::new(deprecated_alias)))
+                }
+            }
+        }
+    });
+
+    let parse_prefix = type_properties.parse_prefix.map(|_| {
+        // Longer strings are tried first, so `<=` isn't read as `<` followed by `=`. The sort is
+        // stable, so strings of the same length keep their declaration order.
+        prefix_entries
+            .sort_by_key(|(serialization, ..)| ::core::cmp::Reverse(serialization.value().len()));
+        let prefix_checks =
+            prefix_entries
+                .iter()
+                .map(|(serialization, ascii_case_insensitive, value)| {
+                    let len = serialization.value().len();
+                    let matches = if *ascii_case_insensitive {
+                        quote! { head.eq_ignore_ascii_case(#serialization) }
+                    } else {
+                        quote! { head == #serialization }
+                    };
+                    quote! {
+                        if let ::core::option::Option::Some(head) = s.get(..#len) {
+                            if #matches {
+                                return ::core::option::Option::Some((#value, &s[#len..]));
+                            }
+                        }
+                    }
+                });
+        quote! {
+            #[allow(clippy::use_self)]
+            #[automatically_derived]
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Parses the longest serialization `s` starts with, and returns the variant along with
+                /// the rest of `s`.
+                pub fn parse_prefix(s: &str) -> ::core::option::Option<(Self, &str)> {
+                    #(#prefix_checks)*
+                    ::core::option::Option::None
+                }
+            }
+        }
+    });
+
+    // Lists parse their items with `FromStr`.
+    if let (true, Some(kw)) = (borrows, type_properties.parse_list) {
+        return Err(syn::Error::new_spanned(
+            kw,
+            "`parse_list` can't be used when the default variant borrows from the input",
+        ));
+    }
 
     Ok(quote! {
         #from_str
         #try_from_str
+        #parse_borrowed
+        #completions
+        #const_from_str
+        #parse_list
+        #parse_with_info
+        #parse_prefix
     })
 }
 
-fn try_from_str(
-    name: &proc_macro2::Ident,
-    impl_generics: &syn::ImplGenerics,
-    ty_generics: &syn::TypeGenerics,
-    where_clause: Option<&syn::WhereClause>,
+/// The `TryFrom` impls for bytes, and with `std` for `OsStr`, `String` and `Cow<str>`, added by
+/// `extra_try_from`. `not_utf8` is the error for input that isn't UTF-8, given as `input: &str`.
+fn extra_try_from(
+    name: &Ident,
+    generics: &syn::Generics,
     default_err_ty: &TokenStream,
+    byte_match: &TokenStream,
+    not_utf8: &TokenStream,
+    strum_module_path: &Path,
 ) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     quote! {
         #[allow(clippy::use_self)]
         #[automatically_derived]
-        impl #impl_generics ::core::convert::TryFrom<&str> for #name #ty_generics #where_clause {
+        impl #impl_generics ::core::convert::TryFrom<&[u8]> for #name #ty_generics #where_clause {
             type Error = #default_err_ty;
 
             #[inline]
-            fn try_from(s: &str) -> ::core::result::Result< #name #ty_generics , <Self as ::core::convert::TryFrom<&str>>::Error> {
-                ::core::str::FromStr::from_str(s)
+            fn try_from(bytes: &[u8]) -> ::core::result::Result< #name #ty_generics , <Self as ::core::convert::TryFrom<&[u8]>>::Error> {
+                #byte_match
+                #strum_module_path::_private::parse_bytes(bytes, |input| #not_utf8)
+            }
+        }
+
+        #strum_module_path::_private_if_std! {
+            #[allow(clippy::use_self)]
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::TryFrom<&#strum_module_path::_private::OsStr> for #name #ty_generics #where_clause {
+                type Error = #default_err_ty;
+
+                #[inline]
+                fn try_from(s: &#strum_module_path::_private::OsStr) -> ::core::result::Result< #name #ty_generics , <Self as ::core::convert::TryFrom<&#strum_module_path::_private::OsStr>>::Error> {
+                    match s.to_str() {
+                        ::core::option::Option::Some(s) => ::core::str::FromStr::from_str(s),
+                        ::core::option::Option::None => {
+                            let input = &*s.to_string_lossy();
+                            ::core::result::Result::Err(#not_utf8)
+                        }
+                    }
+                }
+            }
+
+            #[allow(clippy::use_self)]
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::TryFrom<#strum_module_path::_private::String> for #name #ty_generics #where_clause {
+                type Error = #default_err_ty;
+
+                #[inline]
+                fn try_from(s: #strum_module_path::_private::String) -> ::core::result::Result< #name #ty_generics , <Self as ::core::convert::TryFrom<#strum_module_path::_private::String>>::Error> {
+                    ::core::str::FromStr::from_str(&s)
+                }
+            }
+
+            #[allow(clippy::use_self)]
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::TryFrom<#strum_module_path::_private::Cow<'_, str>> for #name #ty_generics #where_clause {
+                type Error = #default_err_ty;
+
+                #[inline]
+                fn try_from(s: #strum_module_path::_private::Cow<'_, str>) -> ::core::result::Result< #name #ty_generics , <Self as ::core::convert::TryFrom<#strum_module_path::_private::Cow<'_, str>>>::Error> {
+                    ::core::str::FromStr::from_str(&s)
+                }
+            }
+        }
+    }
+}
+
+/// Whether `ty` refers to `lifetime` anywhere, as in `&'a str` or `Cow<'a, str>`.
+fn mentions_lifetime(ty: &Type, lifetime: &Lifetime) -> bool {
+    fn scan(tokens: TokenStream, lifetime: &Lifetime) -> bool {
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            match token {
+                TokenTree::Group(group) if scan(group.stream(), lifetime) => return true,
+                TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                    if matches!(tokens.peek(), Some(TokenTree::Ident(ident)) if *ident == lifetime.ident)
+                    {
+                        return true;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        false
+    }
+
+    scan(ty.to_token_stream(), lifetime)
+}
+
+/// The user supplied function that turns a rejected input into the enum's `parse_err_ty`.
+enum ErrFn {
+    /// `parse_err_fn`, called with the input.
+    Input(Path),
+    /// `parse_err_context_fn`, called with a `ParseErrorContext`. Expects `EXPECTED`, and
+    /// `SUGGEST_WIDTH` if `suggest` is set, to be in scope.
+    Context {
+        path: Path,
+        enum_name: String,
+        suggest: bool,
+    },
+}
+
+impl ErrFn {
+    fn call(&self, input: TokenStream, strum_module_path: &Path) -> TokenStream {
+        match self {
+            ErrFn::Input(path) => quote! { #path(#input) },
+            ErrFn::Context {
+                path,
+                enum_name,
+                suggest: true,
+            } => quote! {
+                #path(#strum_module_path::ParseErrorContext::with_suggestions::<SUGGEST_WIDTH>(
+                    #input, #enum_name, EXPECTED,
+                ))
+            },
+            ErrFn::Context {
+                path,
+                enum_name,
+                suggest: false,
+            } => quote! {
+                #path(#strum_module_path::ParseErrorContext::new(#input, #enum_name, EXPECTED))
+            },
+        }
+    }
+}
+
+/// The fallback of a `default_from_str` variant: the whole input is parsed by the field's own
+/// `FromStr`. Its error is kept as the reason of an `InvalidField`, or converted with `From` when
+/// the enum has its own error type.
+fn default_from_str(
+    name: &Ident,
+    variant: &Variant,
+    err_fn: &Option<ErrFn>,
+    strum_module_path: &Path,
+) -> syn::Result<TokenStream> {
+    let ident = &variant.ident;
+    let (field, field_name, ctor) = match &variant.fields {
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => (
+            &fields.unnamed[0],
+            "0".to_owned(),
+            quote! { #name::#ident(value) },
+        ),
+        Fields::Named(fields) if fields.named.len() == 1 => {
+            let field_ident = fields.named[0].ident.as_ref().unwrap();
+            (
+                &fields.named[0],
+                field_ident.to_string(),
+                quote! { #name::#ident { #field_ident: value } },
+            )
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "`default_from_str` only works on variants with a single field",
+            ))
+        }
+    };
+
+    let ty = &field.ty;
+    let variant_name = ident.to_string();
+    let err = match err_fn {
+        Some(_) => quote! { ::core::convert::From::from(err) },
+        None => quote! {
+            #strum_module_path::ParseError::InvalidField(
+                #strum_module_path::InvalidField::with_reason(input, #variant_name, #field_name, &err),
+            )
+        },
+    };
+
+    Ok(quote! {
+        match <#ty as ::core::str::FromStr>::from_str(input) {
+            ::core::result::Result::Ok(value) => ::core::result::Result::Ok(#ctor),
+            ::core::result::Result::Err(err) => ::core::result::Result::Err(#err),
+        }
+    })
+}
+
+/// A string `from_str` accepts, remembered to detect other variants accepting it too.
+struct Parsed<'a> {
+    value: String,
+    span: Span,
+    ident: &'a Ident,
+    from_case_style: bool,
+    ascii_case_insensitive: bool,
+    normalize: Normalize,
+}
+
+impl Parsed<'_> {
+    /// Whether `from_str` would pick this variant for the input `s`.
+    fn accepts(&self, s: &str) -> bool {
+        let s = self.normalize.apply(s);
+        let value = self.normalize.apply(&self.value);
+        if self.ascii_case_insensitive && !self.normalize.case_insensitive {
+            s.eq_ignore_ascii_case(&value)
+        } else {
+            s == value
+        }
+    }
+}
+
+/// Builds the prefix tree of `names` for `#[strum(unique_prefix)]`. Every prefix of a name is
+/// flattened into a match arm, so looking one up costs a single `match`. Returns the match for
+/// `from_str` and the body of `completions`.
+///
+/// If any name is `ascii_case_insensitive`, the input is lowercased and the arms are keyed on the
+/// lowercased prefixes. Case sensitive names only count when the input also has their exact case,
+/// so where there are any, the arm matches the input itself once more to pick the candidates.
+fn unique_prefix(
+    name: &Ident,
+    mut names: Vec<(String, bool, &Ident, TokenStream)>,
+    ambiguous: Option<TokenStream>,
+    strum_module_path: &Path,
+) -> (TokenStream, TokenStream) {
+    let fold = names
+        .iter()
+        .any(|(_, ascii_case_insensitive, ..)| *ascii_case_insensitive);
+    let key = |s: &str| {
+        if fold {
+            s.to_ascii_lowercase()
+        } else {
+            s.to_owned()
+        }
+    };
+    names.sort_by(|a, b| key(&a.0).cmp(&key(&b.0)).then_with(|| a.0.cmp(&b.0)));
+    names.dedup_by(|a, b| a.0 == b.0);
+    let keys: Vec<String> = names.iter().map(|(s, ..)| key(s)).collect();
+
+    let mut prefixes: Vec<&str> = keys
+        .iter()
+        .flat_map(|s| {
+            s.char_indices()
+                .map(|(i, c)| i + c.len_utf8())
+                .map(move |end| &s[..end])
+        })
+        .collect();
+    prefixes.sort_unstable();
+    prefixes.dedup();
+
+    let all: Vec<&str> = names.iter().map(|(s, ..)| s.as_str()).collect();
+    let mut parse_arms = Vec::new();
+    let mut completion_arms = Vec::new();
+    for prefix in prefixes {
+        let start = keys.iter().position(|s| s.starts_with(prefix)).unwrap();
+        let end = start
+            + keys[start..]
+                .iter()
+                .take_while(|s| s.starts_with(prefix))
+                .count();
+        let range = &names[start..end];
+
+        // What the input is looked up as, given the exact start of the case sensitive names it
+        // matches. Lowercasing ASCII keeps byte offsets, so `prefix.len()` is a char boundary.
+        let lookup = |exact: &str| {
+            let candidates: Vec<_> = range
+                .iter()
+                .filter(|(s, ascii_case_insensitive, ..)| {
+                    *ascii_case_insensitive || s[..prefix.len()] == *exact
+                })
+                .collect();
+            let completions = candidates.iter().map(|(s, ..)| s.as_str());
+            let completion = quote! { &[#(#completions),*] };
+
+            // Names sort before everything they're a prefix of, so a name that is also the start
+            // of other names comes first and is never ambiguous.
+            let whole = candidates.iter().find(|(s, ascii_case_insensitive, ..)| {
+                s.len() == prefix.len() && (*ascii_case_insensitive || s == exact)
+            });
+            let parse = match (whole, candidates.first()) {
+                (_, None) => None,
+                (Some((_, _, ident, params)), _) => Some(quote! {
+                    return ::core::result::Result::Ok(#name::#ident #params)
+                }),
+                (None, Some((_, _, ident, params)))
+                    if candidates.iter().all(|(_, _, other, _)| other == ident) =>
+                {
+                    Some(quote! { return ::core::result::Result::Ok(#name::#ident #params) })
+                }
+                _ => ambiguous.as_ref().map(|ambiguous| {
+                    let candidates = candidates.iter().map(|(s, ..)| s.as_str());
+                    quote! {
+                        return ::core::result::Result::Err(#ambiguous(
+                            #strum_module_path::Ambiguous::new(input, &[#(#candidates),*]),
+                        ))
+                    }
+                }),
+            };
+
+            (parse, completion)
+        };
+
+        let mut exact_starts: Vec<&str> = range
+            .iter()
+            .filter(|(_, ascii_case_insensitive, ..)| !*ascii_case_insensitive)
+            .map(|(s, ..)| &s[..prefix.len()])
+            .collect();
+        exact_starts.sort_unstable();
+        exact_starts.dedup();
+        if !fold || exact_starts.is_empty() {
+            // Without folding, the input is the exact start of every name in the range.
+            let (parse, completion) = lookup(prefix);
+            if let Some(parse) = parse {
+                parse_arms.push(quote! { #prefix => #parse, });
+            }
+            completion_arms.push((prefix, completion));
+        } else {
+            let (parse, completion) = lookup("");
+            let (exact_parses, exact_completions): (Vec<_>, Vec<_>) =
+                exact_starts.iter().map(|exact| lookup(exact)).unzip();
+            let exact_parses = exact_parses
+                .into_iter()
+                .map(|parse| parse.unwrap_or_else(|| quote!({})));
+            let parse = parse.unwrap_or_else(|| quote!({}));
+            parse_arms.push(quote! {
+                #prefix => match input {
+                    #(#exact_starts => #exact_parses,)*
+                    _ => #parse,
+                },
+            });
+            completion_arms.push((
+                prefix,
+                quote! {
+                    match prefix {
+                        #(#exact_starts => #exact_completions,)*
+                        _ => #completion,
+                    }
+                },
+            ));
+        }
+    }
+
+    let (completion_keys, completion_names): (Vec<_>, Vec<_>) = completion_arms.into_iter().unzip();
+    let (abbreviations, completions) = if fold {
+        let max_len = all.iter().map(|s| s.len()).max().unwrap_or(0);
+        (
+            quote! {
+                let mut buf = [0u8; #max_len];
+                if let ::core::option::Option::Some(key) =
+                    #strum_module_path::_private::ascii_lowercase(input, &mut buf)
+                {
+                    match key {
+                        #(#parse_arms)*
+                        _ => {}
+                    }
+                }
+            },
+            quote! {
+                let mut buf = [0u8; #max_len];
+                let key = #strum_module_path::_private::ascii_lowercase(prefix, &mut buf);
+                let names: &'static [&'static str] = match key {
+                    ::core::option::Option::Some("") => NAMES,
+                    #(::core::option::Option::Some(#completion_keys) => #completion_names,)*
+                    _ => &[],
+                };
+            },
+        )
+    } else {
+        (
+            quote! {
+                match input {
+                    #(#parse_arms)*
+                    _ => {}
+                }
+            },
+            quote! {
+                let names: &'static [&'static str] = match prefix {
+                    "" => NAMES,
+                    #(#completion_keys => #completion_names,)*
+                    _ => &[],
+                };
+            },
+        )
+    };
+    let abbreviations = quote! { { #abbreviations } };
+    let completions = quote! {
+        const NAMES: &[&str] = &[#(#all),*];
+        #completions
+        names.iter().copied()
+    };
+
+    (abbreviations, completions)
+}
+
+/// The normalization applied to the input before comparing it to a variant. This mirrors
+/// `strum::_private::Normalize`, which does the same to the input at runtime.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+struct Normalize {
+    trim: bool,
+    case_insensitive: bool,
+    separator_insensitive: bool,
+}
+
+impl Normalize {
+    fn apply(&self, serialization: &str) -> String {
+        let serialization = if self.trim {
+            serialization.trim()
+        } else {
+            serialization
+        };
+
+        let mut output = String::with_capacity(serialization.len());
+        for c in serialization.chars() {
+            if self.separator_insensitive && matches!(c, '-' | '_' | ' ') {
+                continue;
+            }
+
+            if self.case_insensitive {
+                fold_case(c, &mut output);
+            } else {
+                output.push(c);
+            }
+        }
+
+        output
+    }
+}
+
+/// Appends the Unicode full case folding of `c` to `output`, by uppercasing and then lowercasing
+/// it. Must stay in sync with `strum::_private::fold_case`, which folds the input.
+fn fold_case(c: char, output: &mut String) {
+    // The dotless `ı` only folds to itself, but uppercases to `I`.
+    if c == 'ı' {
+        output.push(c);
+        return;
+    }
+
+    for lower in c.to_uppercase().flat_map(char::to_lowercase) {
+        // The capital `ẞ` lowercases to `ß` instead of folding to `ss`.
+        if lower == 'ß' {
+            output.push_str("ss");
+        } else {
+            output.push(lower);
+        }
+    }
+}
+
+/// The serializations of every variant that shares the same normalization.
+struct NormalizedGroup {
+    normalize: Normalize,
+    arms: Vec<TokenStream>,
+    max_len: usize,
+}
+
+/// Matches the input against a `to_string` template like `"Rgb({r}, {g}, {b})"` and parses each
+/// captured piece with the field's `FromStr`. Fields that aren't in the template are defaulted.
+fn field_pattern(
+    name: &Ident,
+    variant: &Variant,
+    to_string: &LitStr,
+    segments: &[Segment],
+    invalid_field: &TokenStream,
+) -> syn::Result<TokenStream> {
+    let ident = &variant.ident;
+    let variant_properties = variant.get_variant_properties()?;
+    let variant_name = ident.to_string();
+
+    let field_names: Vec<String> = match &variant.fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| field.ident.as_ref().unwrap().to_string())
+            .collect(),
+        Fields::Unnamed(fields) => (0..fields.unnamed.len()).map(|i| i.to_string()).collect(),
+        Fields::Unit => Vec::new(),
+    };
+
+    let mut captured: Vec<Option<Ident>> = vec![None; field_names.len()];
+    let mut steps = Vec::new();
+    let mut pending: Option<Ident> = None;
+    for segment in segments {
+        match segment {
+            Segment::Literal(literal) => match pending.take() {
+                None => steps.push(quote! { let rest = rest.strip_prefix(#literal)?; }),
+                Some(capture) => {
+                    steps.push(quote! { let (#capture, rest) = rest.split_once(#literal)?; })
+                }
+            },
+            Segment::Placeholder {
+                name: placeholder,
+                spec,
+            } => {
+                // Padding and other formatting can't be undone before the field's `FromStr`.
+                if let Some(spec) = spec {
+                    return Err(syn::Error::new_spanned(
+                        to_string,
+                        format!(
+                            "`{{{}:{}}}` can't be parsed; remove the format spec or `parse_fields`",
+                            placeholder, spec
+                        ),
+                    ));
+                }
+
+                if pending.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        to_string,
+                        "Placeholders must be separated by some text to be parsed",
+                    ));
+                }
+
+                let index = field_names
+                    .iter()
+                    .position(|field| field == placeholder)
+                    .ok_or_else(|| {
+                        syn::Error::new_spanned(
+                            to_string,
+                            format!("`{{{}}}` doesn't name a field of this variant", placeholder),
+                        )
+                    })?;
+                if captured[index].is_some() {
+                    return Err(syn::Error::new_spanned(
+                        to_string,
+                        format!("`{{{}}}` can only be parsed once", placeholder),
+                    ));
+                }
+
+                let capture = format_ident!("field_{}", placeholder);
+                captured[index] = Some(capture.clone());
+                pending = Some(capture);
             }
         }
     }
+
+    match pending {
+        Some(capture) => steps.push(quote! { let #capture = rest; }),
+        None => steps.push(quote! { if !rest.is_empty() { return ::core::option::Option::None; } }),
+    }
+
+    let captures: Vec<&Ident> = captured.iter().flatten().collect();
+    let mut values = Vec::new();
+    for (index, capture) in captured.iter().enumerate() {
+        let field_name = &field_names[index];
+        let value = match capture {
+            Some(capture) => quote! {
+                match ::core::str::FromStr::from_str(#capture) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(_) => {
+                        let (value, variant, field) = (#capture, #variant_name, #field_name);
+                        return ::core::result::Result::Err(#invalid_field);
+                    }
+                }
+            },
+            None => match &variant.fields {
+                Fields::Named(fields) => {
+                    let meta = fields.named[index].get_variant_inner_properties()?;
+                    match meta.default_with {
+                        Some(default_with) => {
+                            let func = Ident::new(&default_with.value(), default_with.span());
+                            quote! { #func() }
+                        }
+                        None => quote! { Default::default() },
+                    }
+                }
+                _ => match &variant_properties.default_with {
+                    Some(default_with) => {
+                        let func = Ident::new(&default_with.value(), default_with.span());
+                        quote! { #func() }
+                    }
+                    None => quote! { Default::default() },
+                },
+            },
+        };
+        values.push(value);
+    }
+
+    let constructor = match &variant.fields {
+        Fields::Named(fields) => {
+            let idents = fields
+                .named
+                .iter()
+                .map(|field| field.ident.as_ref().unwrap());
+            quote! { #name::#ident { #(#idents: #values),* } }
+        }
+        _ => quote! { #name::#ident ( #(#values),* ) },
+    };
+
+    Ok(quote! {
+        #[allow(unused_variables)]
+        if let ::core::option::Option::Some((#(#captures,)*)) = (|| {
+            let rest = s;
+            #(#steps)*
+            ::core::option::Option::Some((#(#captures,)*))
+        })() {
+            return ::core::result::Result::Ok(#constructor);
+        }
+    })
 }
@@ -3,6 +3,7 @@ use quote::quote;
 use syn::{Fields, Ident, Variant};
 
 pub mod as_ref_str;
+pub mod debug;
 pub mod display;
 pub mod from_string;
 pub mod to_string;
@@ -26,7 +26,7 @@ pub fn to_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         if variant_properties.to_string.is_none() && variant_properties.default.is_some() {
             match &variant.fields {
                 Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
-                    arms.push(quote! { #name::#ident(ref s) => ::std::string::String::from(s) });
+                    arms.push(quote! { #name::#ident(ref s) => ::std::string::ToString::to_string(s) });
                     continue;
                 }
                 _ => {
@@ -38,6 +38,8 @@ pub fn to_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
             }
         }
 
+        variant_properties.reject_reserved_placeholders("ToString")?;
+
         // Look at all the serialize attributes.
         let output = variant_properties.get_preferred_name(
             type_properties.case_style,
@@ -6,6 +6,7 @@ mod tests {
     use strum::EnumString;
 
     #[derive(Debug, Eq, PartialEq, EnumString, strum::Display)]
+    #[strum(extra_try_from, parse_list)]
     enum Color {
         Red,
         Blue {
@@ -24,9 +25,117 @@ mod tests {
         assert_eq!(Color::Yellow, Color::from_str("yellow").unwrap());
     }
 
+    #[test]
+    fn not_found_no_std() {
+        let strum::ParseError::VariantNotFound(err) = Color::from_str("green").unwrap_err() else {
+            panic!("expected VariantNotFound");
+        };
+        assert_eq!(5, err.input_len());
+        assert_eq!(Some(&"Red"), err.expected().first());
+    }
+
+    #[derive(Debug, Eq, PartialEq, EnumString)]
+    #[strum(trim, case_insensitive, separator_insensitive, extra_try_from)]
+    enum Shade {
+        DarkBlue,
+        LightGray,
+    }
+
+    #[test]
+    fn normalized_no_std() {
+        assert_eq!(Shade::DarkBlue, Shade::from_str(" dark-blue ").unwrap());
+        assert_eq!(Shade::LightGray, Shade::from_str("LIGHT_GRAY").unwrap());
+    }
+
+    #[derive(Debug, Eq, PartialEq, EnumString)]
+    #[strum(use_decision_tree, ascii_case_insensitive)]
+    enum Tree {
+        Oak,
+        Ash,
+        Aspen,
+    }
+
+    #[test]
+    fn decision_tree_no_std() {
+        assert_eq!(Tree::Aspen, Tree::from_str("ASPEN").unwrap());
+        assert_eq!(Tree::Ash, Tree::from_str("ash").unwrap());
+        assert!(Tree::from_str("asp").is_err());
+    }
+
+    #[derive(Debug, Eq, PartialEq, EnumString)]
+    enum Code {
+        #[strum(pattern = "E1??")]
+        Info,
+        #[strum(pattern = "E*")]
+        Error,
+    }
+
+    #[test]
+    fn pattern_no_std() {
+        assert_eq!(Code::Info, Code::from_str("E101").unwrap());
+        assert_eq!(Code::Error, Code::from_str("E1010").unwrap());
+        assert!(Code::from_str("W101").is_err());
+    }
+
+    #[derive(strum::Display)]
+    enum Reading {
+        #[strum(to_string = "{0}/{1}")]
+        Pressure(u8, u8),
+    }
+
+    /// Collects formatted output without allocating.
+    struct Buf {
+        bytes: [u8; 32],
+        len: usize,
+    }
+
+    impl core::fmt::Write for Buf {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.bytes[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+            self.len += s.len();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn display_tuple_no_std() {
+        use core::fmt::Write;
+
+        let mut buf = Buf {
+            bytes: [0; 32],
+            len: 0,
+        };
+        write!(buf, "[{:>8}]", Reading::Pressure(120, 80)).unwrap();
+        assert_eq!(b"[  120/80]", &buf.bytes[..buf.len]);
+    }
+
+    #[test]
+    fn parse_list_no_std() {
+        let mut list = Color::parse_list("Red|yellow|y", "|").reject_duplicates();
+        assert_eq!(Some(Ok(Color::Red)), list.next());
+        assert_eq!(Some(Ok(Color::Yellow)), list.next());
+        assert!(matches!(
+            list.next(),
+            Some(Err(strum::ParseError::Duplicate(_)))
+        ));
+        assert_eq!(None, list.next());
+    }
+
     #[test]
     fn try_from_str_no_std() {
         use core::convert::TryFrom;
         assert_eq!(Color::Yellow, Color::try_from("yellow").unwrap());
     }
+
+    #[test]
+    fn try_from_bytes_no_std() {
+        use core::convert::TryFrom;
+        assert_eq!(Color::Black, Color::try_from(&b"BLK"[..]).unwrap());
+        assert_eq!(Shade::DarkBlue, Shade::try_from(&b"dark blue"[..]).unwrap());
+
+        assert!(matches!(
+            Color::try_from(&b"Re\xffd"[..]),
+            Err(strum::ParseError::VariantNotFound(_))
+        ));
+    }
 }
@@ -13,3 +13,64 @@ pub enum Color {
     #[strum(disabled)]
     Green(String),
 }
+
+/// Reserved placeholders in `to_string` are only expanded by `Display`. Every other derive that
+/// would return the template as is rejects them.
+///
+/// ```compile_fail
+/// #[derive(strum::AsRefStr)]
+/// enum Spell {
+///     #[strum(to_string = "{__variant}!")]
+///     Fire,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[derive(strum::IntoStaticStr)]
+/// enum Spell {
+///     #[strum(to_string = "{__variant}!")]
+///     Fire,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[derive(strum::VariantNames)]
+/// enum Spell {
+///     #[strum(to_string = "{__discriminant}")]
+///     Fire,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[derive(strum::EnumString)]
+/// enum Spell {
+///     #[strum(to_string = "{__variant}!")]
+///     Fire,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[derive(strum::EnumMessage)]
+/// enum Spell {
+///     #[strum(message = "Burns", to_string = "{__message}")]
+///     Fire,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[derive(strum::StrumDebug)]
+/// enum Spell {
+///     #[strum(to_string = "{__variant}!")]
+///     Fire,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[derive(strum::ToString)]
+/// enum Spell {
+///     #[strum(to_string = "{__discriminant}")]
+///     Fire,
+/// }
+/// ```
+#[cfg(doctest)]
+pub struct ReservedPlaceholders;
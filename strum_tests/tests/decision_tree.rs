@@ -0,0 +1,65 @@
+use std::convert::TryFrom;
+use strum::{EnumIter, EnumString, IntoEnumIterator, IntoStaticStr};
+
+mod core {} // ensure macros call `::core`
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, EnumString, EnumIter, IntoStaticStr)]
+#[strum(use_decision_tree, extra_try_from)]
+enum Color {
+    #[strum(ascii_case_insensitive)]
+    Blue,
+    Red,
+    #[strum(serialize = "rose", serialize = "Rust")]
+    Rose,
+    #[strum(ascii_case_insensitive)]
+    Black,
+    Brown,
+    #[strum(serialize = "")]
+    Empty,
+}
+
+#[test]
+fn decision_tree() {
+    for color in Color::iter() {
+        let name: &'static str = color.into();
+        assert_eq!(Ok(color), name.parse::<Color>());
+        assert_eq!(Ok(color), Color::try_from(name.as_bytes()));
+    }
+
+    assert_eq!(Ok(Color::Rose), "rose".parse::<Color>());
+    assert_eq!(Ok(Color::Rose), "Rust".parse::<Color>());
+    assert_eq!(Ok(Color::Blue), "bLuE".parse::<Color>());
+    assert_eq!(Ok(Color::Black), "BLACK".parse::<Color>());
+    assert!("RED".parse::<Color>().is_err());
+    assert!("brown ".parse::<Color>().is_err());
+    assert!("Rosa".parse::<Color>().is_err());
+    assert!("Bluf".parse::<Color>().is_err());
+}
+
+#[test]
+fn decision_tree_big() {
+    #[derive(Debug, PartialEq, Eq, Clone, EnumString)]
+    #[strum(
+        use_decision_tree,
+        extra_try_from,
+        ascii_case_insensitive,
+        prefix = "var."
+    )]
+    enum Enum {
+        Var1,
+        Var2,
+        Var3,
+        Var10,
+        Var11,
+        Var12,
+        Xar1,
+        Vbr1,
+    }
+
+    assert_eq!("var.vAr2".parse::<Enum>().unwrap(), Enum::Var2);
+    assert_eq!("var.VAR11".parse::<Enum>().unwrap(), Enum::Var11);
+    assert_eq!("var.xar1".parse::<Enum>().unwrap(), Enum::Xar1);
+    assert_eq!(Enum::try_from(&b"var.VBR1"[..]).unwrap(), Enum::Vbr1);
+    assert!("var.Var4".parse::<Enum>().is_err());
+    assert!("Var1".parse::<Enum>().is_err());
+}
@@ -2,18 +2,13 @@ use strum::EnumString;
 
 mod core {} // ensure macros call `::core`
 
-#[derive(Debug, Eq, PartialEq, EnumString, strum::Display)]
+#[derive(Debug, Default, Eq, PartialEq, EnumString, strum::Display)]
 enum InnerColor {
+    #[default]
     Violet,
     Fuchsia,
 }
 
-impl Default for InnerColor {
-    fn default() -> Self {
-        InnerColor::Violet
-    }
-}
-
 #[derive(Debug, Eq, PartialEq, EnumString, strum::Display)]
 enum Color {
     #[strum(to_string = "RedRed")]
@@ -185,3 +180,143 @@ fn transparent_string_named_field() {
         )
     );
 }
+
+#[derive(strum::Display)]
+enum Point {
+    #[strum(to_string = "({0}, {1})")]
+    Flat(i32, i32),
+    #[strum(to_string = "({x}, {y}, {z})")]
+    Space { x: i32, y: i32, z: i32 },
+    #[strum(to_string = "été {0}")]
+    Summer(u8),
+}
+
+#[test]
+fn interpolated_formatters() {
+    let flat = Point::Flat(1, -2);
+    assert_eq!("(1, -2)", format!("{}", flat));
+    assert_eq!("   (1, -2)", format!("{:>10}", flat));
+    assert_eq!("(1, -2)   ", format!("{:10}", flat));
+    assert_eq!("*(1, -2)**", format!("{:*^10}", flat));
+    assert_eq!("(1,", format!("{:.3}", flat));
+    assert_eq!("  (1,", format!("{:>5.3}", flat));
+    assert_eq!("(1, -2)", format!("{:3}", flat));
+
+    let space = Point::Space { x: 1, y: 2, z: 3 };
+    assert_eq!("(1, 2, 3)", space.to_string());
+    assert_eq!("-(1, 2, 3)-", format!("{:-^11}", space));
+    assert_eq!("(1, 2", format!("{:.5}", space));
+
+    // Width and precision count chars, not bytes.
+    assert_eq!("  été 7", format!("{:>7}", Point::Summer(7)));
+    assert_eq!("ét", format!("{:.2}", Point::Summer(7)));
+}
+
+#[derive(strum::Display)]
+#[strum(serialize_all = "snake_case", prefix = "err_")]
+enum Failure {
+    #[strum(to_string_alt = "The disk is full")]
+    DiskFull,
+    #[strum(to_string = "timeout_{0}", to_string_alt = "Timed out after {0}s")]
+    Timeout(u32),
+    #[strum(to_string_alt = "Missing {path}")]
+    Missing {
+        path: &'static str,
+    },
+    Unknown,
+}
+
+#[test]
+fn alternate_display() {
+    assert_eq!("err_disk_full", format!("{}", Failure::DiskFull));
+    assert_eq!("The disk is full", format!("{:#}", Failure::DiskFull));
+    assert_eq!("err_timeout_5", Failure::Timeout(5).to_string());
+    assert_eq!("Timed out after 5s", format!("{:#}", Failure::Timeout(5)));
+    assert_eq!(
+        "Missing /etc",
+        format!("{:#}", Failure::Missing { path: "/etc" })
+    );
+    assert_eq!(
+        "err_missing",
+        format!("{}", Failure::Missing { path: "/etc" })
+    );
+
+    // Variants without an alternate form ignore the flag.
+    assert_eq!("err_unknown", format!("{:#}", Failure::Unknown));
+    assert_eq!(
+        "  Timed out after 5s",
+        format!("{:>#20}", Failure::Timeout(5))
+    );
+}
+
+struct Location {
+    file: &'static str,
+    line: u32,
+}
+
+#[derive(strum::Display)]
+#[strum(serialize_all = "kebab-case", to_string_template = "{__variant}: {0}")]
+#[repr(u8)]
+enum Io {
+    NotFound(&'static str),
+    #[strum(serialize = "denied")]
+    PermissionDenied(&'static str),
+    #[strum(to_string = "{0.file}:{0.line:03} ({__discriminant})")]
+    Parse(Location),
+    #[strum(message = "Disk full", to_string = "{__message} [{__variant}]")]
+    NoSpace = 9,
+    #[strum(to_string = "{__variant}#{__discriminant}")]
+    Other,
+    #[strum(to_string = "retry in {delay.0:?}")]
+    Retry {
+        delay: (u16, u16),
+    },
+}
+
+#[test]
+fn template_placeholders() {
+    assert_eq!("not-found: a.txt", Io::NotFound("a.txt").to_string());
+    assert_eq!("denied: b.txt", Io::PermissionDenied("b.txt").to_string());
+    assert_eq!(
+        "  denied: b.txt",
+        format!("{:>15}", Io::PermissionDenied("b.txt"))
+    );
+    let location = Location {
+        file: "main.rs",
+        line: 7,
+    };
+    assert_eq!("main.rs:007 (2)", Io::Parse(location).to_string());
+    assert_eq!("Disk full [no-space]", Io::NoSpace.to_string());
+    assert_eq!("other#10", Io::Other.to_string());
+    assert_eq!("retry in 3", Io::Retry { delay: (3, 4) }.to_string());
+}
+
+#[derive(strum::Display)]
+#[strum(to_string_template = "{__variant}={__discriminant}")]
+enum Stage {
+    Draft,
+    #[strum(disabled)]
+    #[allow(dead_code)]
+    Review,
+    Published,
+}
+
+#[test]
+fn template_discriminant_after_disabled() {
+    assert_eq!("Draft=0", Stage::Draft.to_string());
+    assert_eq!("Published=2", Stage::Published.to_string());
+}
+
+#[derive(strum::Display)]
+#[strum(prefix = "[", suffix = "]", to_string_template = "{__variant}")]
+enum Tag {
+    Bold,
+    #[strum(to_string = "i")]
+    Italic,
+}
+
+#[test]
+fn template_with_affixes() {
+    assert_eq!("[Bold]", Tag::Bold.to_string());
+    assert_eq!("[i]", Tag::Italic.to_string());
+}
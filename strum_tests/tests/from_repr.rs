@@ -64,3 +64,19 @@ fn crate_module_path_test() {
     assert_eq!(Week::from_repr(6), Some(Week::Saturday));
     assert_eq!(Week::from_repr(7), None);
 }
+
+#[test]
+fn disabled_test() {
+    #[derive(Debug, FromRepr, PartialEq)]
+    enum Retired {
+        First,
+        #[strum(disabled)]
+        #[allow(dead_code)]
+        Second,
+        Third,
+    }
+
+    assert_eq!(Retired::from_repr(0), Some(Retired::First));
+    assert_eq!(Retired::from_repr(1), None);
+    assert_eq!(Retired::from_repr(2), Some(Retired::Third));
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+use std::ffi::OsStr;
 use std::str::FromStr;
 use strum::EnumString;
 
@@ -44,6 +46,31 @@ where
     assert_eq!(a, std::convert::TryFrom::try_from(from).unwrap());
 }
 
+fn assert_extra_try_from<'a, T>(a: T, from: &'a str)
+where
+    T: PartialEq
+        + std::convert::TryFrom<&'a [u8]>
+        + std::convert::TryFrom<&'a OsStr>
+        + std::convert::TryFrom<String>
+        + std::convert::TryFrom<Cow<'a, str>>
+        + std::fmt::Debug,
+    <T as std::convert::TryFrom<&'a [u8]>>::Error: std::fmt::Debug,
+    <T as std::convert::TryFrom<&'a OsStr>>::Error: std::fmt::Debug,
+    <T as std::convert::TryFrom<String>>::Error: std::fmt::Debug,
+    <T as std::convert::TryFrom<Cow<'a, str>>>::Error: std::fmt::Debug,
+{
+    assert_eq!(a, std::convert::TryFrom::try_from(from.as_bytes()).unwrap());
+    assert_eq!(
+        a,
+        std::convert::TryFrom::try_from(OsStr::new(from)).unwrap()
+    );
+    assert_eq!(a, std::convert::TryFrom::try_from(from.to_owned()).unwrap());
+    assert_eq!(
+        a,
+        std::convert::TryFrom::try_from(Cow::Borrowed(from)).unwrap()
+    );
+}
+
 #[test]
 fn color_simple() {
     assert_from_str(Color::Red, "Red");
@@ -93,6 +120,28 @@ fn color_ascii_case_insensitive() {
     assert_from_str(Color::Black, "bLaCk");
 }
 
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(extra_try_from)]
+enum Signal {
+    Start,
+    #[strum(serialize = "halt", serialize = "stop")]
+    Stop,
+    #[strum(ascii_case_insensitive)]
+    Pause {
+        seconds: usize,
+    },
+    #[strum(default)]
+    Other(String),
+}
+
+#[test]
+fn extra_try_from() {
+    assert_extra_try_from(Signal::Start, "Start");
+    assert_extra_try_from(Signal::Stop, "halt");
+    assert_extra_try_from(Signal::Pause { seconds: 0 }, "PAUSE");
+    assert_extra_try_from(Signal::Other(String::from("resume")), "resume");
+}
+
 #[derive(Debug, Eq, PartialEq, EnumString)]
 #[strum(serialize_all = "snake_case")]
 enum Brightness {
@@ -125,11 +174,53 @@ enum Week {
 #[test]
 fn week_not_found() {
     assert_eq!(
-        Result::Err(::strum::ParseError::VariantNotFound),
+        Result::Err(::strum::ParseError::VariantNotFound(
+            ::strum::VariantNotFound::new(
+                "Humpday",
+                &[
+                    "Sunday",
+                    "Monday",
+                    "Tuesday",
+                    "Wednesday",
+                    "Thursday",
+                    "Friday",
+                    "Saturday"
+                ]
+            )
+        )),
         Week::from_str("Humpday")
     );
 }
 
+#[test]
+fn week_not_found_details() {
+    let strum::ParseError::VariantNotFound(err) = Week::from_str("Humpday").unwrap_err() else {
+        panic!("expected VariantNotFound");
+    };
+    assert_eq!("Humpday", err.input());
+    assert_eq!(7, err.input_len());
+    assert_eq!(Some(&"Sunday"), err.expected().first());
+}
+
+#[test]
+fn color_not_found_display() {
+    // Disabled variants aren't listed.
+    #[allow(dead_code)]
+    #[derive(Debug, EnumString)]
+    enum Color {
+        #[strum(serialize = "r", to_string = "red")]
+        Red,
+        #[strum(disabled)]
+        Blue,
+        Green,
+    }
+
+    assert_eq!(
+        r#"Matching variant not found for "blue", expected one of: "r", "red", "Green""#,
+        Color::from_str("blue").unwrap_err().to_string()
+    );
+}
+
 #[test]
 fn week_found() {
     assert_from_str(Week::Sunday, "Sunday");
@@ -141,6 +232,56 @@ fn week_found() {
     assert_from_str(Week::Saturday, "Saturday");
 }
 
+#[allow(dead_code)]
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(suggest)]
+enum Suggest {
+    Red,
+    Read,
+    #[strum(serialize = "grn", to_string = "Green")]
+    Green,
+    Yellow,
+    #[strum(disabled)]
+    Blue,
+}
+
+fn suggestions(s: &str) -> Vec<&'static str> {
+    let strum::ParseError::VariantNotFound(err) = Suggest::from_str(s).unwrap_err() else {
+        panic!("expected VariantNotFound");
+    };
+    err.suggestions().to_vec()
+}
+
+#[test]
+fn suggest_closest() {
+    assert_eq!(vec!["Green"], suggestions("Greem"));
+    assert_eq!(vec!["grn", "Green"], suggestions("Gren"));
+    assert_eq!(vec!["Yellow"], suggestions("yelow"));
+    assert_eq!(vec!["Yellow"], suggestions("Yelolw"));
+    assert_eq!(vec!["grn"], suggestions("GRNE"));
+}
+
+#[test]
+fn suggest_ties() {
+    assert_eq!(vec!["Red", "Read"], suggestions("Rad"));
+    assert_eq!(vec!["Red"], suggestions("RED"));
+}
+
+#[test]
+fn suggest_nothing_close() {
+    assert!(suggestions("Purple").is_empty());
+    assert!(suggestions("Blue").is_empty());
+    assert!(suggestions("").is_empty());
+}
+
+#[test]
+fn suggest_display() {
+    assert_eq!(
+        r#"Matching variant not found for "Rad", expected one of: "Red", "Read", "grn", "Green", "Yellow"; did you mean "Red" or "Read"?"#,
+        Suggest::from_str("Rad").unwrap_err().to_string()
+    );
+}
+
 #[derive(Debug, Eq, PartialEq, EnumString)]
 enum Lifetime<'a> {
     Life(&'a str),
@@ -274,3 +415,973 @@ fn case_custom_parse_error() {
         r.unwrap_err()
     );
 }
+
+#[derive(Debug, Eq, PartialEq, EnumString, strum::Display)]
+#[strum(parse_fields)]
+enum Shape {
+    #[strum(to_string = "Rect({width}x{height})")]
+    Rect {
+        width: u32,
+        height: u32,
+    },
+    #[strum(to_string = "Circle(r={0})")]
+    Circle(u16),
+    #[strum(to_string = "Label: {text}")]
+    Label {
+        text: String,
+        color: Option<u8>,
+    },
+    #[strum(serialize = "dot", to_string = "Dot at {1},{0}")]
+    Dot(i8, i8),
+    Empty,
+}
+
+#[test]
+fn parse_fields_round_trip() {
+    for shape in [
+        Shape::Rect {
+            width: 3,
+            height: 4,
+        },
+        Shape::Circle(15),
+        Shape::Label {
+            text: "hello, world".into(),
+            color: None,
+        },
+        Shape::Dot(-1, 7),
+        Shape::Empty,
+    ] {
+        let s = shape.to_string();
+        assert_eq!(shape, Shape::from_str(&s).unwrap());
+    }
+}
+
+#[test]
+fn parse_fields_aliases_use_defaults() {
+    assert_from_str(Shape::Dot(0, 0), "dot");
+}
+
+#[test]
+fn parse_fields_no_match() {
+    let strum::ParseError::VariantNotFound(err) = Shape::from_str("Rect(3x4").unwrap_err() else {
+        panic!("expected VariantNotFound");
+    };
+    // Templates aren't valid input themselves, so only the exact serializations are listed.
+    assert_eq!(["dot", "Empty"], err.expected());
+    assert!(Shape::from_str("Rect(3x4) ").is_err());
+}
+
+#[test]
+fn parse_fields_invalid_field() {
+    let strum::ParseError::InvalidField(err) = Shape::from_str("Rect(3xfour)").unwrap_err() else {
+        panic!("expected InvalidField");
+    };
+    assert_eq!("Rect", err.variant());
+    assert_eq!("height", err.field());
+    assert_eq!("four", err.input());
+    assert_eq!(
+        r#"Invalid value "four" for field `height` of variant `Rect`"#,
+        err.to_string()
+    );
+
+    let strum::ParseError::InvalidField(err) = Shape::from_str("Dot at 1,x").unwrap_err() else {
+        panic!("expected InvalidField");
+    };
+    assert_eq!("0", err.field());
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+enum ParseFieldsVariant {
+    #[strum(parse_fields, to_string = "A({0})")]
+    A(u8),
+    #[strum(to_string = "B({0})")]
+    B(u8),
+}
+
+#[test]
+fn parse_fields_variant_level() {
+    assert_from_str(ParseFieldsVariant::A(5), "A(5)");
+    assert_from_str(ParseFieldsVariant::B(0), "B({0})");
+    assert!(ParseFieldsVariant::from_str("B(5)").is_err());
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(trim, case_insensitive, separator_insensitive)]
+enum Normalized {
+    DarkBlue,
+    #[strum(serialize = "Ünïcode")]
+    Unicode,
+    #[strum(serialize = "ΣΟΦΟΣ")]
+    Wise,
+    #[strum(serialize = "Straße")]
+    Street,
+    #[strum(trim = false)]
+    NoTrim,
+    #[strum(
+        serialize = "Exact_Case",
+        case_insensitive = false,
+        separator_insensitive = false
+    )]
+    ExactCase,
+    #[strum(ascii_case_insensitive, case_insensitive = false)]
+    AsciiOnly,
+}
+
+#[test]
+fn normalized_trim_case_separators() {
+    assert_from_str(Normalized::DarkBlue, "DarkBlue");
+    assert_from_str(Normalized::DarkBlue, "  Dark-Blue ");
+    assert_from_str(Normalized::DarkBlue, "dark_blue");
+    assert_from_str(Normalized::DarkBlue, "DARK BLUE");
+    assert_from_str(Normalized::DarkBlue, "\tdarkblue\n");
+    assert!(Normalized::from_str("dark.blue").is_err());
+}
+
+#[test]
+fn normalized_unicode_case() {
+    assert_from_str(Normalized::Unicode, "ÜNÏCODE");
+    assert_from_str(Normalized::Unicode, " ünïcode");
+    assert!(Normalized::from_str("unicode").is_err());
+    // Case folding, unlike lowercasing, equates the final sigma and `ß` with `ss`.
+    assert_from_str(Normalized::Wise, "σοφος");
+    assert_from_str(Normalized::Street, "STRASSE");
+    assert_from_str(Normalized::Street, "strasse");
+    assert_from_str(Normalized::Street, "STRAẞE");
+}
+
+#[test]
+fn normalized_variant_overrides() {
+    assert_from_str(Normalized::NoTrim, "no_trim");
+    // Spaces count as separators, but other whitespace is only removed by `trim`.
+    assert!(Normalized::from_str("\tNoTrim").is_err());
+
+    assert_from_str(Normalized::ExactCase, " Exact_Case ");
+    assert!(Normalized::from_str("exact_case").is_err());
+    assert!(Normalized::from_str("ExactCase").is_err());
+
+    assert_from_str(Normalized::AsciiOnly, " ascii-only");
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString, strum::Display)]
+#[strum(serialize_all = "snake_case", parse_any_case)]
+enum AnyCase {
+    HttpServer,
+    #[strum(serialize = "db")]
+    Database,
+}
+
+#[test]
+fn parse_any_case() {
+    assert_eq!("http_server", AnyCase::HttpServer.to_string());
+    for input in [
+        "http_server",
+        "httpServer",
+        "HttpServer",
+        "HTTP_SERVER",
+        "http-server",
+        "HTTP-SERVER",
+        "httpserver",
+        "HTTPSERVER",
+        "Http Server",
+        "Http-Server",
+    ] {
+        assert_from_str(AnyCase::HttpServer, input);
+    }
+
+    // Explicit serializations are left alone.
+    assert_from_str(AnyCase::Database, "db");
+    assert!(AnyCase::from_str("DB").is_err());
+    assert!(AnyCase::from_str("Database").is_err());
+}
+
+#[test]
+fn parse_any_case_expected() {
+    let Err(strum::ParseError::VariantNotFound(err)) = AnyCase::from_str("ftp") else {
+        panic!("expected VariantNotFound");
+    };
+    assert_eq!(&["http_server", "db"], err.expected());
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(
+    serialize_all = "snake_case",
+    parse_case_styles("camelCase", "SCREAMING_SNAKE_CASE")
+)]
+enum SomeCases {
+    LoadBalancer,
+}
+
+#[test]
+fn parse_case_styles() {
+    assert_from_str(SomeCases::LoadBalancer, "load_balancer");
+    assert_from_str(SomeCases::LoadBalancer, "loadBalancer");
+    assert_from_str(SomeCases::LoadBalancer, "LOAD_BALANCER");
+    assert!(SomeCases::from_str("LoadBalancer").is_err());
+    assert!(SomeCases::from_str("load-balancer").is_err());
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "lowercase", unique_prefix)]
+enum Command {
+    Stash,
+    #[strum(serialize = "status", serialize = "st")]
+    Status,
+    Stat,
+    Commit {
+        amend: bool,
+    },
+    #[strum(disabled)]
+    Checkout,
+}
+
+#[test]
+fn unique_prefix() {
+    assert_from_str(Command::Status, "status");
+    assert_from_str(Command::Status, "statu");
+    assert_from_str(Command::Status, "st");
+    assert_from_str(Command::Stat, "stat");
+    assert_from_str(Command::Stash, "stas");
+    assert_from_str(Command::Commit { amend: false }, "c");
+    assert!(Command::from_str("checkout").is_err());
+    assert!(Command::from_str("").is_err());
+    assert!(Command::from_str("x").is_err());
+}
+
+#[test]
+fn unique_prefix_ambiguous() {
+    let Err(strum::ParseError::Ambiguous(err)) = Command::from_str("sta") else {
+        panic!("expected Ambiguous");
+    };
+    assert_eq!("sta", err.input());
+    assert_eq!(&["stash", "stat", "status"], err.candidates());
+
+    let Err(strum::ParseError::Ambiguous(err)) = Command::from_str("s") else {
+        panic!("expected Ambiguous");
+    };
+    assert_eq!(&["st", "stash", "stat", "status"], err.candidates());
+}
+
+#[test]
+fn unique_prefix_completions() {
+    assert_eq!(
+        vec!["commit", "st", "stash", "stat", "status"],
+        Command::completions("").collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec!["stash", "stat", "status"],
+        Command::completions("sta").collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec!["status"],
+        Command::completions("statu").collect::<Vec<_>>()
+    );
+    assert_eq!(0, Command::completions("x").count());
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(prefix = "--", unique_prefix)]
+enum Flag {
+    Verbose,
+    Version,
+    #[strum(default)]
+    Other(String),
+}
+
+#[test]
+fn unique_prefix_with_prefix_and_default() {
+    assert_from_str(Flag::Verbose, "--Verb");
+    assert_from_str(Flag::Other("--Ver".into()), "--Ver");
+    assert_from_str(Flag::Other("Verb".into()), "Verb");
+    assert_eq!(
+        vec!["--Verbose", "--Version"],
+        Flag::completions("--V").collect::<Vec<_>>()
+    );
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(unique_prefix, ascii_case_insensitive)]
+enum Git {
+    Status,
+    Stash,
+    #[strum(ascii_case_insensitive = false)]
+    Stage,
+}
+
+#[test]
+fn unique_prefix_ascii_case_insensitive() {
+    assert_from_str(Git::Status, "stat");
+    assert_from_str(Git::Status, "STAT");
+    assert_from_str(Git::Stash, "sTaSh");
+    // `Stage` only counts as a candidate when written in its own case.
+    assert_from_str(Git::Stage, "Stag");
+    assert!(Git::from_str("stag").is_err());
+
+    let Err(strum::ParseError::Ambiguous(err)) = Git::from_str("STA") else {
+        panic!("expected Ambiguous");
+    };
+    assert_eq!(&["Stash", "Status"], err.candidates());
+    let Err(strum::ParseError::Ambiguous(err)) = Git::from_str("Sta") else {
+        panic!("expected Ambiguous");
+    };
+    assert_eq!(&["Stage", "Stash", "Status"], err.candidates());
+}
+
+#[test]
+fn unique_prefix_ascii_case_insensitive_completions() {
+    assert_eq!(
+        vec!["Stash", "Status"],
+        Git::completions("ST").collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec!["Stage", "Stash", "Status"],
+        Git::completions("St").collect::<Vec<_>>()
+    );
+    assert_eq!(3, Git::completions("").count());
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "snake_case")]
+enum Repeated {
+    // Repeating a string within one variant is not a collision.
+    #[strum(serialize = "one", serialize = "one", serialize = "uno")]
+    One,
+    #[strum(ascii_case_insensitive)]
+    Two,
+}
+
+#[test]
+fn repeated_serialization() {
+    assert_from_str(Repeated::One, "one");
+    assert_from_str(Repeated::One, "uno");
+    assert_from_str(Repeated::Two, "TWO");
+
+    let Err(strum::ParseError::VariantNotFound(err)) = Repeated::from_str("three") else {
+        panic!("expected VariantNotFound");
+    };
+    assert_eq!(&["one", "uno", "two"], err.expected());
+}
+
+#[test]
+fn try_from_invalid_utf8() {
+    use std::convert::TryFrom;
+    assert_eq!(Signal::Start, Signal::try_from(&b"Start"[..]).unwrap());
+
+    // Input that isn't UTF-8 doesn't reach the default variant.
+    let Err(strum::ParseError::VariantNotFound(err)) = Signal::try_from(&b"\xffStart"[..]) else {
+        panic!("expected VariantNotFound");
+    };
+    assert_eq!("\u{fffd}Start", err.input());
+}
+
+#[cfg(unix)]
+#[test]
+fn try_from_non_unicode_os_str() {
+    use std::convert::TryFrom;
+    use std::os::unix::ffi::OsStrExt;
+
+    assert!(matches!(
+        Signal::try_from(OsStr::from_bytes(b"Start\xff")),
+        Err(strum::ParseError::VariantNotFound(_))
+    ));
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "lowercase", const_from_str)]
+enum Level {
+    Error,
+    #[strum(serialize = "warn", serialize = "warning")]
+    Warn,
+    #[strum(ascii_case_insensitive)]
+    Info,
+    #[strum(disabled)]
+    #[allow(dead_code)]
+    Trace,
+}
+
+#[test]
+fn const_from_str() {
+    const LEVEL: Level = Level::from_str_const("warn");
+    const INFO: Option<Level> = Level::try_from_str_const("INFO");
+    const TRACE: Option<Level> = Level::try_from_str_const("trace");
+
+    assert_eq!(Level::Warn, LEVEL);
+    assert_eq!(Some(Level::Info), INFO);
+    assert_eq!(None, TRACE);
+    assert_eq!(Some(Level::Warn), Level::try_from_str_const("warning"));
+    assert_eq!(Some(Level::Error), Level::try_from_str_const("error"));
+    assert_eq!(None, Level::try_from_str_const("ERROR"));
+    assert_eq!(None, Level::try_from_str_const("war"));
+}
+
+#[test]
+#[should_panic(expected = "no variant of `Level` matches the string")]
+fn const_from_str_panics() {
+    Level::from_str_const("debug");
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(prefix = "log.", optional_affixes, const_from_str)]
+enum LogTarget {
+    Stderr,
+}
+
+#[test]
+fn const_from_str_affixes() {
+    const TARGET: LogTarget = LogTarget::from_str_const("log.Stderr");
+    assert_eq!(LogTarget::Stderr, TARGET);
+    assert_eq!(
+        Some(LogTarget::Stderr),
+        LogTarget::try_from_str_const("Stderr")
+    );
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive, parse_list)]
+enum Permission {
+    Read,
+    #[strum(serialize = "write", serialize = "w")]
+    Write,
+    Admin,
+}
+
+#[test]
+fn parse_list() {
+    let list: Vec<_> = Permission::parse_list("read,W,ADMIN", ",").collect();
+    assert_eq!(
+        vec![
+            Ok(Permission::Read),
+            Ok(Permission::Write),
+            Ok(Permission::Admin)
+        ],
+        list
+    );
+
+    let mut list = Permission::parse_list("read, write,root", ",");
+    assert_eq!(Some(Ok(Permission::Read)), list.next());
+    assert!(list.next().unwrap().is_err());
+    let Some(Err(strum::ParseError::VariantNotFound(err))) = list.next() else {
+        panic!("expected VariantNotFound");
+    };
+    assert_eq!("root", err.input());
+    assert_eq!(None, list.next());
+
+    assert_eq!(0, Permission::parse_list("", ",").count());
+    assert_eq!(2, Permission::parse_list("read::write", "::").count());
+}
+
+#[test]
+fn collect_list() {
+    assert_eq!(
+        Ok(vec![Permission::Write, Permission::Write]),
+        Permission::collect_list("write; w", "; ")
+    );
+    assert!(Permission::collect_list::<Vec<_>>("write,,read", ",").is_err());
+}
+
+#[test]
+fn parse_list_reject_duplicates() {
+    let list: Vec<_> = Permission::parse_list("read,admin,write", ",")
+        .reject_duplicates()
+        .collect();
+    assert!(list.iter().all(Result::is_ok));
+
+    let mut list = Permission::parse_list("write,read,W,READ", ",").reject_duplicates();
+    assert_eq!(Some(Ok(Permission::Write)), list.next());
+    assert_eq!(Some(Ok(Permission::Read)), list.next());
+    let Some(Err(strum::ParseError::Duplicate(err))) = list.next() else {
+        panic!("expected Duplicate");
+    };
+    assert_eq!("W", err.input());
+    let Some(Err(strum::ParseError::Duplicate(err))) = list.next() else {
+        panic!("expected Duplicate");
+    };
+    assert_eq!("READ", err.input());
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(parse_list)]
+enum Topping {
+    Cheese,
+    #[strum(default)]
+    Custom(String),
+}
+
+#[test]
+fn parse_list_default_variant() {
+    let list: Result<Vec<_>, _> = Topping::parse_list("Cheese,olive,olive", ",")
+        .reject_duplicates()
+        .collect();
+    assert!(matches!(list, Err(strum::ParseError::Duplicate(_))));
+
+    let list: Result<Vec<_>, _> = Topping::parse_list("olive,Cheese,basil", ",")
+        .reject_duplicates()
+        .collect();
+    assert_eq!(
+        Ok(vec![
+            Topping::Custom("olive".into()),
+            Topping::Cheese,
+            Topping::Custom("basil".into())
+        ]),
+        list
+    );
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString, strum::Display)]
+enum Port {
+    #[strum(serialize = "http")]
+    Http,
+    #[strum(default_from_str)]
+    Number(u16),
+}
+
+#[test]
+fn default_from_str() {
+    assert_from_str(Port::Http, "http");
+    assert_from_str(Port::Number(8080), "8080");
+    assert_eq!("8080", Port::Number(8080).to_string());
+    assert_eq!(Ok(Port::Number(22)), Port::Number(22).to_string().parse());
+
+    let Err(strum::ParseError::InvalidField(err)) = Port::from_str("https") else {
+        panic!("expected InvalidField");
+    };
+    assert_eq!("https", err.input());
+    assert_eq!("Number", err.variant());
+    assert_eq!("0", err.field());
+    assert_eq!(Some("invalid digit found in string"), err.reason());
+    assert_eq!(
+        r#"Invalid value "https" for field `0` of variant `Number`: invalid digit found in string"#,
+        err.to_string()
+    );
+}
+
+#[derive(Debug, Eq, PartialEq)]
+enum IdError {
+    Parse(std::num::ParseIntError),
+    Unknown(String),
+}
+
+impl From<std::num::ParseIntError> for IdError {
+    fn from(err: std::num::ParseIntError) -> Self {
+        IdError::Parse(err)
+    }
+}
+
+fn unknown_id(s: &str) -> IdError {
+    IdError::Unknown(s.to_owned())
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+enum Id {
+    Root,
+    // Types that only implement `From<&str>` keep using `default`.
+    #[strum(default)]
+    User {
+        id: Box<str>,
+    },
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(parse_err_ty = IdError, parse_err_fn = unknown_id, parse_list)]
+enum NumericId {
+    Root,
+    #[strum(default_from_str)]
+    Numbered(u32),
+}
+
+#[test]
+fn default_from_str_custom_error() {
+    assert_eq!(Ok(Id::Root), Id::from_str("Root"));
+    assert_eq!(Ok(Id::User { id: "alice".into() }), Id::from_str("alice"));
+
+    assert_eq!(Ok(NumericId::Root), NumericId::from_str("Root"));
+    assert_eq!(Ok(NumericId::Numbered(7)), NumericId::from_str("7"));
+    assert!(matches!(NumericId::from_str("bob"), Err(IdError::Parse(_))));
+
+    // Other errors still go through `parse_err_fn`.
+    let list: Result<Vec<NumericId>, _> = NumericId::parse_list("1,2,1", ",")
+        .reject_duplicates()
+        .collect();
+    assert_eq!(Err(IdError::Unknown("1".into())), list);
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct UnknownValue {
+    kind: &'static str,
+    value: String,
+    expected: &'static [&'static str],
+    suggestions: Vec<&'static str>,
+}
+
+fn unknown_value(ctx: strum::ParseErrorContext<'_>) -> UnknownValue {
+    UnknownValue {
+        kind: ctx.enum_name(),
+        value: ctx.input().to_owned(),
+        expected: ctx.expected(),
+        suggestions: ctx.suggestions().to_vec(),
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(
+    parse_err_ty = UnknownValue,
+    parse_err_context_fn = unknown_value,
+    parse_list
+)]
+enum Tool {
+    Brush,
+    #[strum(serialize = "pencil", serialize = "pen")]
+    Pencil,
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(suggest, parse_err_ty = UnknownValue, parse_err_context_fn = unknown_value)]
+enum Fill {
+    Solid,
+    Hatched,
+}
+
+#[test]
+fn parse_err_context_fn() {
+    assert_eq!(Ok(Tool::Pencil), Tool::from_str("pen"));
+    assert_eq!(
+        Err(UnknownValue {
+            kind: "Tool",
+            value: "eraser".into(),
+            expected: &["Brush", "pencil", "pen"],
+            suggestions: vec![],
+        }),
+        Tool::from_str("eraser")
+    );
+
+    assert_eq!(
+        Err(UnknownValue {
+            kind: "Fill",
+            value: "Sold".into(),
+            expected: &["Solid", "Hatched"],
+            suggestions: vec!["Solid"],
+        }),
+        Fill::from_str("Sold")
+    );
+
+    let list: Result<Vec<Tool>, _> = Tool::parse_list("pen,Brush,pencil", ",")
+        .reject_duplicates()
+        .collect();
+    assert_eq!("pencil", list.unwrap_err().value);
+}
+
+#[test]
+fn parse_err_context_not_found() {
+    let ctx =
+        strum::ParseErrorContext::with_suggestions::<8>("Hached", "Fill", &["Solid", "Hatched"]);
+    let err = ctx.not_found();
+    assert_eq!("Hached", err.input());
+    assert_eq!(["Hatched"], err.suggestions());
+}
+
+#[derive(
+    Debug, Eq, PartialEq, EnumString, strum::Display, strum::VariantNames, strum::EnumMessage,
+)]
+#[strum(ascii_case_insensitive, parse_with_info)]
+enum Region {
+    #[strum(
+        serialize = "eu-west",
+        deprecated_alias = "europe",
+        deprecated_alias = "eu"
+    )]
+    EuWest,
+    #[strum(trim, deprecated_alias = "us")]
+    UsEast {
+        zone: u8,
+    },
+    Asia,
+}
+
+#[test]
+fn deprecated_alias() {
+    assert_eq!(Ok(Region::EuWest), Region::from_str("eu-west"));
+    assert_eq!(Ok(Region::EuWest), Region::from_str("Europe"));
+    assert_eq!(Ok(Region::UsEast { zone: 0 }), Region::from_str(" US "));
+
+    let (region, info) = Region::parse_with_info("EU").unwrap();
+    assert_eq!(Region::EuWest, region);
+    assert_eq!(Some("eu"), info.deprecated_alias());
+    assert!(info.is_deprecated());
+
+    let (region, info) = Region::parse_with_info(" us").unwrap();
+    assert_eq!(Region::UsEast { zone: 0 }, region);
+    assert_eq!(Some("us"), info.deprecated_alias());
+
+    let (region, info) = Region::parse_with_info("asia").unwrap();
+    assert_eq!(Region::Asia, region);
+    assert!(!info.is_deprecated());
+}
+
+#[test]
+fn deprecated_alias_not_advertised() {
+    use strum::{EnumMessage, VariantNames};
+
+    assert_eq!(["eu-west", "UsEast", "Asia"], Region::VARIANTS);
+    assert_eq!(["eu-west"], Region::EuWest.get_serializations());
+    assert_eq!("eu-west", Region::EuWest.to_string());
+
+    let strum::ParseError::VariantNotFound(err) = Region::from_str("mars").unwrap_err() else {
+        unreachable!()
+    };
+    assert_eq!(["eu-west", "UsEast", "Asia"], err.expected());
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+enum Status {
+    #[strum(serialize = "E100")]
+    Continue,
+    #[strum(serialize = "E404", pattern = "E4??")]
+    ClientError,
+    #[strum(ascii_case_insensitive, pattern = "E5*", pattern = "fatal: *")]
+    ServerError { code: u16 },
+    #[strum(pattern = "E*")]
+    Other,
+}
+
+#[test]
+fn pattern() {
+    assert_eq!(Ok(Status::Continue), Status::from_str("E100"));
+    assert_eq!(Ok(Status::ClientError), Status::from_str("E404"));
+    assert_eq!(Ok(Status::ClientError), Status::from_str("E418"));
+    assert_eq!(Ok(Status::ClientError), Status::from_str("E4é!"));
+    assert_eq!(Ok(Status::ServerError { code: 0 }), Status::from_str("E5"));
+    assert_eq!(
+        Ok(Status::ServerError { code: 0 }),
+        Status::from_str("e503")
+    );
+    assert_eq!(
+        Ok(Status::ServerError { code: 0 }),
+        Status::from_str("FATAL: disk full")
+    );
+
+    // The earlier pattern wins, and `?` matches exactly one char.
+    assert_eq!(Ok(Status::Other), Status::from_str("E4000"));
+    assert_eq!(Ok(Status::Other), Status::from_str("E"));
+    assert!(Status::from_str("e404").is_err());
+    assert!(Status::from_str("W100").is_err());
+}
+
+#[test]
+fn pattern_expected() {
+    let strum::ParseError::VariantNotFound(err) = Status::from_str("W100").unwrap_err() else {
+        unreachable!()
+    };
+    assert_eq!(["E100", "E404", "ServerError", "Other"], err.expected());
+}
+
+#[test]
+fn pattern_keeps_variant_name() {
+    // Without a `serialize`, the variant still parses from its name, as written by `Display`.
+    assert_eq!(
+        Ok(Status::ServerError { code: 0 }),
+        Status::from_str("ServerError")
+    );
+    assert_eq!(Ok(Status::Other), Status::from_str("Other"));
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(suggest)]
+enum Channel {
+    Stable,
+    #[strum(pattern = "nightly-*")]
+    Nightly,
+}
+
+#[test]
+fn pattern_not_suggested() {
+    let strum::ParseError::VariantNotFound(err) = Channel::from_str("nightly*").unwrap_err() else {
+        unreachable!()
+    };
+    assert_eq!(["Stable", "Nightly"], err.expected());
+    assert_eq!(["Nightly"], err.suggestions());
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "lowercase", parse_repr)]
+#[repr(i8)]
+enum Severity {
+    Debug = -1,
+    Info,
+    #[strum(serialize = "warn", serialize = "5")]
+    Warn,
+    Error = 10,
+    #[strum(disabled)]
+    #[allow(dead_code)]
+    Fatal,
+}
+
+#[test]
+fn parse_repr() {
+    assert_eq!(Ok(Severity::Warn), Severity::from_str("warn"));
+    assert_eq!(Ok(Severity::Debug), Severity::from_str("-1"));
+    assert_eq!(Ok(Severity::Info), Severity::from_str("0"));
+    assert_eq!(Ok(Severity::Warn), Severity::from_str("1"));
+    assert_eq!(Ok(Severity::Error), Severity::from_str("10"));
+
+    // Serializations come first.
+    assert_eq!(Ok(Severity::Warn), Severity::from_str("5"));
+
+    assert!(Severity::from_str("11").is_err());
+    assert!(Severity::from_str("300").is_err());
+    assert!(Severity::from_str(" 1").is_err());
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(parse_repr)]
+enum Unrepresented {
+    First,
+    Second,
+}
+
+#[test]
+fn parse_repr_default_type() {
+    assert_eq!(Ok(Unrepresented::Second), Unrepresented::from_str("1"));
+    assert!(Unrepresented::from_str("-1").is_err());
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(parse_repr)]
+enum Retired {
+    First,
+    #[strum(disabled)]
+    #[allow(dead_code)]
+    Second,
+    Third,
+}
+
+#[test]
+fn parse_repr_after_disabled() {
+    // The disabled variant keeps its discriminant.
+    assert_eq!(Ok(Retired::First), Retired::from_str("0"));
+    assert!(Retired::from_str("1").is_err());
+    assert_eq!(Ok(Retired::Third), Retired::from_str("2"));
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(serialize_all = "lowercase", parse_with_info, parse_borrowed)]
+enum Keyword<'a> {
+    Let,
+    #[strum(deprecated_alias = "function")]
+    Fn,
+    #[strum(default)]
+    Ident(&'a str),
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(prefix = "--", unique_prefix, parse_prefix)]
+enum Arg<'a> {
+    #[strum(serialize = "verbose")]
+    Verbose,
+    #[strum(default)]
+    Other { raw: Cow<'a, str> },
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(parse_list, parse_borrowed)]
+enum Owned<'a> {
+    Borrowed(&'a str),
+    #[strum(default)]
+    Other(String),
+}
+
+#[test]
+fn parse_borrowed() {
+    let source = String::from("let counter");
+    let tokens: Vec<Keyword> = source
+        .split(' ')
+        .map(|word| Keyword::parse_borrowed(word).unwrap())
+        .collect();
+    assert_eq!(vec![Keyword::Let, Keyword::Ident("counter")], tokens);
+    assert_eq!(Ok(Keyword::Fn), Keyword::try_from("fn"));
+
+    let (keyword, info) = Keyword::parse_with_info(&source[4..]).unwrap();
+    assert_eq!(Keyword::Ident("counter"), keyword);
+    assert!(!info.is_deprecated());
+    assert!(Keyword::parse_with_info("function")
+        .unwrap()
+        .1
+        .is_deprecated());
+
+    // Without `parse_borrowed`, the borrowing default variant is still built by `TryFrom`.
+    assert_eq!(Ok(Arg::Verbose), Arg::try_from("--verb"));
+    assert_eq!(
+        Ok(Arg::Other {
+            raw: Cow::Borrowed("-v")
+        }),
+        Arg::try_from("-v")
+    );
+}
+
+#[test]
+fn parse_borrowed_without_borrowing_default() {
+    assert_eq!(Ok(Owned::Borrowed("")), Owned::from_str("Borrowed"));
+    assert_eq!(
+        Ok(Owned::Other("x".into())),
+        Owned::parse_borrowed(&String::from("x"))
+    );
+    assert_eq!(
+        Ok(vec![Owned::Borrowed("")]),
+        Owned::collect_list("Borrowed", ",")
+    );
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+#[strum(parse_prefix)]
+enum Operator {
+    #[strum(serialize = "<")]
+    Less,
+    #[strum(serialize = "<=")]
+    LessEqual,
+    #[strum(serialize = "<<=")]
+    ShiftLeftAssign,
+    #[strum(serialize = "<<")]
+    ShiftLeft,
+    #[strum(serialize = "=")]
+    Assign,
+    #[strum(serialize = "and", ascii_case_insensitive)]
+    And,
+    #[strum(serialize = "é")]
+    Acute,
+    #[strum(default)]
+    Other(String),
+}
+
+#[test]
+fn parse_prefix() {
+    assert_eq!(
+        Some((Operator::LessEqual, " b")),
+        Operator::parse_prefix("<= b")
+    );
+    assert_eq!(Some((Operator::Less, " b")), Operator::parse_prefix("< b"));
+    assert_eq!(
+        Some((Operator::ShiftLeftAssign, "1")),
+        Operator::parse_prefix("<<=1")
+    );
+    assert_eq!(
+        Some((Operator::ShiftLeft, "<")),
+        Operator::parse_prefix("<<<")
+    );
+    assert_eq!(Some((Operator::Assign, "")), Operator::parse_prefix("="));
+    assert_eq!(Some((Operator::And, " x")), Operator::parse_prefix("AND x"));
+    assert_eq!(Some((Operator::Acute, "t")), Operator::parse_prefix("ét"));
+
+    // The default variant never matches a prefix.
+    assert_eq!(None, Operator::parse_prefix("x <"));
+    assert_eq!(None, Operator::parse_prefix(""));
+    assert_eq!(None, Operator::parse_prefix("\u{e8}"));
+}
+
+#[test]
+fn parse_prefix_affixes() {
+    assert_eq!(
+        Some((Arg::Verbose, " file")),
+        Arg::parse_prefix("--verbose file")
+    );
+    assert_eq!(None, Arg::parse_prefix("verbose"));
+    assert_eq!(None, Arg::parse_prefix("--verb"));
+}
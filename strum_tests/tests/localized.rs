@@ -0,0 +1,85 @@
+use strum::{Display, Localized};
+
+#[derive(Localized, Display)]
+#[strum(serialize_all = "snake_case")]
+enum Color {
+    #[strum(to_string(locale = "fr") = "Rouge", to_string(locale = "de") = "Rot")]
+    #[strum(message = "Stop", message(locale = "fr") = "Arrêt")]
+    Red,
+    #[strum(to_string(locale = "es") = "Verde")]
+    DarkGreen,
+    #[strum(to_string = "rgb({0}, {1}, {2})")]
+    Rgb(u8, u8, u8),
+    #[strum(to_string = "named", to_string(locale = "fr") = "nommée")]
+    Named { name: String },
+    #[strum(disabled)]
+    #[allow(dead_code)]
+    Hidden,
+}
+
+#[test]
+fn locales() {
+    assert_eq!(["fr", "de", "es"], Color::LOCALES);
+}
+
+#[test]
+fn translated() {
+    assert_eq!(Some("Rouge"), Color::Red.to_localized("fr"));
+    assert_eq!(Some("Rot"), Color::Red.to_localized("de"));
+    assert_eq!(Some("Verde"), Color::DarkGreen.to_localized("es"));
+    let named = Color::Named {
+        name: "teal".into(),
+    };
+    assert_eq!(Some("nommée"), named.to_localized("fr"));
+}
+
+#[test]
+fn fallback() {
+    assert_eq!(Some("red"), Color::Red.to_localized("es"));
+    assert_eq!(Some("dark_green"), Color::DarkGreen.to_localized("fr"));
+    assert_eq!(None, Color::Rgb(1, 2, 3).to_localized("fr"));
+    assert_eq!(None, Color::Hidden.to_localized("fr"));
+}
+
+#[test]
+fn messages() {
+    assert_eq!(Some("Arrêt"), Color::Red.get_localized_message("fr"));
+    assert_eq!(Some("Stop"), Color::Red.get_localized_message("de"));
+    assert_eq!(None, Color::DarkGreen.get_localized_message("fr"));
+}
+
+#[derive(Localized, Display)]
+#[strum(prefix = "cmd_", strict_locales)]
+enum Command {
+    #[strum(to_string(locale = "fr") = "ouvrir")]
+    Open,
+    #[strum(to_string = "close {0}", to_string(locale = "fr") = "fermer")]
+    Close(u8),
+}
+
+#[test]
+fn strict() {
+    assert_eq!(["fr"], Command::LOCALES);
+    assert_eq!(Some("ouvrir"), Command::Open.to_localized("fr"));
+    assert_eq!(Some("cmd_Open"), Command::Open.to_localized("it"));
+    assert_eq!(None, Command::Close(1).to_localized("it"));
+    assert_eq!(Some("fermer"), Command::Close(1).to_localized("fr"));
+    assert_eq!("cmd_close 1", Command::Close(1).to_string());
+}
+
+#[derive(Localized, Display)]
+#[strum(to_string_template = "{__variant}!")]
+enum Alert {
+    #[strum(to_string(locale = "fr") = "Feu !")]
+    Fire,
+    #[strum(to_string = "flood")]
+    Flood,
+}
+
+#[test]
+fn template_fallback() {
+    assert_eq!("Fire!", Alert::Fire.to_string());
+    assert_eq!(Some("Feu !"), Alert::Fire.to_localized("fr"));
+    assert_eq!(None, Alert::Fire.to_localized("de"));
+    assert_eq!(Some("flood"), Alert::Flood.to_localized("de"));
+}
@@ -33,3 +33,22 @@ fn from_str_with_phf_big() {
     }
     assert_eq!("vAr2".parse::<Enum>().unwrap(), Enum::Var2);
 }
+
+#[cfg(feature = "test_phf")]
+#[test]
+fn from_str_with_phf_mixed_case() {
+    #[derive(Debug, PartialEq, Eq, Clone, strum::EnumString)]
+    #[strum(use_phf)]
+    enum Color {
+        #[strum(ascii_case_insensitive, serialize = "green", serialize = "GREEN")]
+        Green,
+        #[strum(ascii_case_insensitive)]
+        DarkBlue,
+        Red,
+    }
+    assert_eq!("GrEeN".parse::<Color>().unwrap(), Color::Green);
+    assert_eq!("dARKbLUE".parse::<Color>().unwrap(), Color::DarkBlue);
+    assert_eq!("Red".parse::<Color>().unwrap(), Color::Red);
+    assert!("red".parse::<Color>().is_err());
+    assert!("DarkBlueish".parse::<Color>().is_err());
+}
@@ -1,3 +1,4 @@
+use std::str::FromStr;
 use strum::{Display, EnumString};
 use strum_macros::AsRefStr;
 
@@ -33,3 +34,97 @@ fn prefix_green_default() {
         (Color::Green("green".into())).as_ref()
     );
 }
+
+#[test]
+fn prefix_round_trip() {
+    let red = Color::Red.to_string();
+    assert!(matches!(Color::from_str(&red), Ok(Color::Red)));
+    assert!(matches!(Color::from_str("colour/RedRed"), Ok(Color::Red)));
+    assert!(matches!(Color::from_str("colour/y"), Ok(Color::Yellow)));
+    assert!(matches!(
+        Color::from_str("colour/blue"),
+        Ok(Color::Blue { hue: 0 })
+    ));
+}
+
+#[test]
+fn prefix_required() {
+    // Without the prefix the input falls through to the default variant, untouched.
+    assert!(matches!(Color::from_str("RedRed"), Ok(Color::Green(s)) if s == "RedRed"));
+    assert!(matches!(Color::from_str("colour/teal"), Ok(Color::Green(s)) if s == "colour/teal"));
+}
+
+#[derive(Debug, PartialEq, EnumString, Display)]
+#[strum(prefix = "tag:")]
+enum Tag {
+    Alpha,
+    #[strum(serialize = "b")]
+    Beta,
+}
+
+#[test]
+fn prefix_not_found() {
+    assert_eq!(Ok(Tag::Beta), Tag::from_str("tag:b"));
+    let Err(strum::ParseError::VariantNotFound(err)) = Tag::from_str("Alpha") else {
+        panic!("expected VariantNotFound");
+    };
+    assert_eq!("Alpha", err.input());
+    assert_eq!(&["tag:Alpha", "tag:b"], err.expected());
+}
+
+#[derive(Debug, PartialEq, EnumString, Display)]
+#[strum(prefix = "tag:", optional_affixes)]
+enum OptionalTag {
+    Alpha,
+    Beta,
+}
+
+#[test]
+fn prefix_optional() {
+    assert_eq!(Ok(OptionalTag::Alpha), OptionalTag::from_str("tag:Alpha"));
+    assert_eq!(Ok(OptionalTag::Alpha), OptionalTag::from_str("Alpha"));
+    assert_eq!(
+        Ok(OptionalTag::Beta),
+        OptionalTag::Beta.to_string().parse::<OptionalTag>()
+    );
+    assert!(OptionalTag::from_str("tag:tag:Alpha").is_err());
+}
+
+#[derive(Debug, PartialEq, EnumString)]
+#[strum(prefix = "x_", suffix = ";", trim, parse_with_info)]
+enum Trimmed {
+    Red,
+    #[strum(deprecated_alias = "crimson")]
+    Scarlet,
+    #[strum(trim = false)]
+    Exact,
+}
+
+#[test]
+fn prefix_trimmed() {
+    assert_eq!(Ok(Trimmed::Red), Trimmed::from_str("x_Red;"));
+    assert_eq!(Ok(Trimmed::Red), Trimmed::from_str(" x_Red; "));
+    assert_eq!(Ok(Trimmed::Red), Trimmed::from_str("x_Red ;"));
+    assert_eq!(Ok(Trimmed::Scarlet), Trimmed::from_str("\tx_crimson;"));
+    let (value, info) = Trimmed::parse_with_info(" x_crimson; ").unwrap();
+    assert_eq!(Trimmed::Scarlet, value);
+    assert_eq!(Some("crimson"), info.deprecated_alias());
+    assert_eq!(Ok(Trimmed::Exact), Trimmed::from_str("x_Exact;"));
+    assert!(Trimmed::from_str(" x_Exact;").is_err());
+    assert!(Trimmed::from_str(" Red ").is_err());
+}
+
+#[derive(Debug, PartialEq, EnumString)]
+#[strum(prefix = "x_", optional_affixes, trim)]
+enum OptionalTrimmed {
+    Red,
+}
+
+#[test]
+fn prefix_optional_trimmed() {
+    assert_eq!(
+        Ok(OptionalTrimmed::Red),
+        OptionalTrimmed::from_str(" x_Red ")
+    );
+    assert_eq!(Ok(OptionalTrimmed::Red), OptionalTrimmed::from_str(" Red"));
+}
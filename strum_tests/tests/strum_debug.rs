@@ -0,0 +1,59 @@
+use strum::StrumDebug;
+
+mod core {} // ensure macros call `::core`
+
+#[derive(StrumDebug)]
+#[allow(dead_code)]
+#[strum(serialize_all = "kebab-case", prefix = "ev:")]
+enum Event<T> {
+    Started,
+    #[strum(serialize = "halt")]
+    Stopped,
+    #[strum(to_string = "moved")]
+    Moved {
+        x: i32,
+        y: i32,
+    },
+    #[strum(to_string = "payload of {0}")]
+    Payload(T),
+    #[strum(transparent)]
+    Custom(String),
+    #[strum(disabled)]
+    Internal {
+        r#type: u8,
+    },
+}
+
+#[test]
+fn unit_variants() {
+    assert_eq!("ev:started", format!("{:?}", Event::<u8>::Started));
+    assert_eq!("ev:halt", format!("{:?}", Event::<u8>::Stopped));
+}
+
+#[test]
+fn data_variants() {
+    assert_eq!(
+        "ev:moved { x: 1, y: -1 }",
+        format!("{:?}", Event::<u8>::Moved { x: 1, y: -1 })
+    );
+    assert_eq!(
+        "ev:payload([1, 2])",
+        format!("{:?}", Event::Payload([1, 2]))
+    );
+    assert_eq!(
+        "ev:moved {\n    x: 1,\n    y: 2,\n}",
+        format!("{:#?}", Event::<u8>::Moved { x: 1, y: 2 })
+    );
+}
+
+#[test]
+fn transparent_and_disabled() {
+    assert_eq!(
+        "\"done\"",
+        format!("{:?}", Event::<u8>::Custom("done".into()))
+    );
+    assert_eq!(
+        "Internal { type: 3 }",
+        format!("{:?}", Event::<u8>::Internal { r#type: 3 })
+    );
+}
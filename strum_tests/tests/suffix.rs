@@ -1,3 +1,4 @@
+use std::str::FromStr;
 use strum::{Display, EnumString};
 use strum_macros::AsRefStr;
 
@@ -42,3 +43,46 @@ fn suffix_green_default() {
     assert_eq!(String::from("basic-green"), c.to_string());
     assert_eq!("Green.color", c.as_ref());
 }
+
+#[test]
+fn suffix_round_trip() {
+    assert!(matches!(Color::from_str("RedRed.color"), Ok(Color::Red)));
+    assert!(matches!(Color::from_str("y.color"), Ok(Color::Yellow)));
+    assert!(matches!(Color::from_str("yellow"), Ok(Color::Green(s)) if s == "yellow"));
+}
+
+#[derive(Debug, PartialEq, EnumString, Display)]
+#[strum(prefix = "<", suffix = ">")]
+enum Wrapped {
+    Open,
+    Closed,
+}
+
+#[test]
+fn suffix_with_prefix() {
+    for wrapped in [Wrapped::Open, Wrapped::Closed] {
+        assert_eq!(
+            Ok(&wrapped),
+            wrapped.to_string().parse::<Wrapped>().as_ref()
+        );
+    }
+
+    assert!(Wrapped::from_str("<Open").is_err());
+    assert!(Wrapped::from_str("Open>").is_err());
+    assert!(Wrapped::from_str("Open").is_err());
+}
+
+#[derive(Debug, PartialEq, EnumString, Display)]
+#[strum(suffix = ".color", optional_affixes)]
+enum OptionalSuffix {
+    Red,
+}
+
+#[test]
+fn suffix_optional() {
+    assert_eq!(
+        Ok(OptionalSuffix::Red),
+        OptionalSuffix::from_str("Red.color")
+    );
+    assert_eq!(Ok(OptionalSuffix::Red), OptionalSuffix::from_str("Red"));
+}